@@ -9,7 +9,7 @@ fn main() {
 
             match gregorian_date {
                 Ok(date) => {
-                    println!("Gregorian Date: {}", date.to_string());
+                    println!("Gregorian Date: {}", date);
                 }
                 Err(convert_err) => {
                     eprintln!("Failed to convert to Gregorian date: {:?}", convert_err);
@@ -31,17 +31,15 @@ fn main() {
 
             match gregorian_date {
                 Ok(date) => {
-                    println!("Gregorian Date: {}", date.to_string());
+                    println!("Gregorian Date: {}", date);
                 }
                 Err(convert_err) => {
                     eprintln!("Failed to convert to Gregorian date: {:?}", convert_err);
-                    return;
                 }
             }
         }
         Err(err) => {
             eprintln!("DateError: {:?}", err);
-            return;
         }
     }
 }
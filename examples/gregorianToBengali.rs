@@ -7,17 +7,15 @@ fn main() {
             let bengali_date = get_bengali_date_from_gregorian(english_date);
             match bengali_date {
                 Ok(date) => {
-                    println!("{}", date.to_string());
+                    println!("{}", date);
                 }
                 Err(convert_err) => {
                     eprintln!("Failed to convert to Bengali date: {:?}", convert_err);
-                    return;
                 }
             }
         }
         Err(err) => {
             eprintln!("DateError: {:?}", err);
-            return;
         }
     }
 }
@@ -3,7 +3,7 @@ fn main() {
     let today = get_today_bengali_date();
     match today {
         Ok(bengali_date) => {
-            println!("{}", bengali_date.to_string());
+            println!("{}", bengali_date);
         }
         Err(err) => {
             eprintln!("Date error: {:?}", err);
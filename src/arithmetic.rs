@@ -0,0 +1,714 @@
+//! # Arithmetic: Adding days, months and years to the dates
+//! The `arithmetic` module provides the arithmetic of the `EnglishDate` and `BengaliDate` structs.
+//! The `Days` struct is the duration that can be added to or subtracted from a date.
+//! The `Overflow` enum decides what happens when the day does not exist in the resulting month.
+//! The `DateSpan` struct is the distance between two dates in days and in years, months and days.
+
+use std::ops::{Add, RangeInclusive, Sub};
+
+use crate::calendar;
+use crate::date::{BengaliDate, DateError, DateField, EnglishDate};
+use crate::months::{BengaliMonths, EnglishMonths, Month};
+use crate::rata_die::{self, RataDie};
+
+/// # `Days`: The duration in days.
+/// The struct is used with the `+` and `-` operators of the dates.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Days(i64);
+
+impl Days {
+    /// Create a duration
+    /// # Arguments
+    /// * `days` - i64
+    /// # Returns
+    /// * `Days` - The duration
+    /// # Example
+    /// ```
+    /// use ponjika::arithmetic::Days;
+    /// let days = Days::new(30);
+    /// assert_eq!(days.get_days(), 30);
+    /// ```
+    pub fn new(days: i64) -> Self {
+        Days(days)
+    }
+
+    /// Get the number of days of the selected duration
+    /// # Returns
+    /// * `i64` - The number of days
+    pub fn get_days(&self) -> i64 {
+        self.0
+    }
+}
+
+/// # `Overflow`: The policy when a day does not exist in the resulting month.
+/// Adding a month to 31 Bhadra in the 1987 rules, for example, leads to Ashwin, which has only 30 days.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Overflow {
+    /// Use the last day of the month, 31 Bhadra becomes 30 Ashwin.
+    #[default]
+    Clamp,
+    /// Return `DateError::DayOutOfMonth`.
+    Reject,
+    /// Carry the extra days into the next month, 31 Bhadra becomes 1 Kartik.
+    RollOver,
+}
+
+/// # `DateSpan`: The distance between two dates.
+/// The struct keeps the exact number of days and the breakdown into whole years, months and days
+/// in the calendar of the dates. All the parts are negative when the end is before the start.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct DateSpan {
+    total_days: i64,
+    years: i64,
+    months: i64,
+    days: i64,
+}
+
+impl DateSpan {
+    /// Get the exact number of days of the selected span
+    /// # Returns
+    /// * `i64` - The number of days
+    /// # Example
+    /// ```
+    /// use ponjika::date::BengaliDate;
+    /// use ponjika::months::BengaliMonths;
+    /// let today = BengaliDate::create_date(20, BengaliMonths::Chaitra, 1430).unwrap();
+    /// let new_year = BengaliDate::create_date(1, BengaliMonths::Baishakh, 1431).unwrap();
+    /// assert_eq!(today.until(&new_year).unwrap().get_total_days(), 11);
+    /// ```
+    pub fn get_total_days(&self) -> i64 {
+        self.total_days
+    }
+
+    /// Get the whole years of the selected span
+    /// # Returns
+    /// * `i64` - The number of years
+    pub fn get_years(&self) -> i64 {
+        self.years
+    }
+
+    /// Get the whole months left after the years of the selected span
+    /// # Returns
+    /// * `i64` - The number of months, between -11 and 11
+    pub fn get_months(&self) -> i64 {
+        self.months
+    }
+
+    /// Get the days left after the years and months of the selected span
+    /// # Returns
+    /// * `i64` - The number of days
+    pub fn get_days(&self) -> i64 {
+        self.days
+    }
+
+    /// Get the years, months and days of the selected span
+    /// # Returns
+    /// * `(i64, i64, i64)` - The years, months and days
+    /// # Example
+    /// ```
+    /// use ponjika::date::BengaliDate;
+    /// use ponjika::months::BengaliMonths;
+    /// let birthday = BengaliDate::create_date(25, BengaliMonths::Baishakh, 1400).unwrap();
+    /// let today = BengaliDate::create_date(10, BengaliMonths::Shrawan, 1431).unwrap();
+    /// assert_eq!(birthday.until(&today).unwrap().get_parts(), (31, 2, 16));
+    /// ```
+    pub fn get_parts(&self) -> (i64, i64, i64) {
+        (self.years, self.months, self.days)
+    }
+
+    /// Check whether the end of the selected span is before its start
+    /// # Returns
+    /// * `bool` - `true` if the span goes back in time
+    pub fn is_negative(&self) -> bool {
+        self.total_days < 0
+    }
+}
+
+/// Measure the span from a date to another date of the same calendar
+/// # Note
+/// * The months are counted from the start as in `add_months` with `Overflow::Clamp`, forward or backward
+/// * Adding the years and months and then the days to the start gives the end
+fn measure<T>(
+    start: &T,
+    end: &T,
+    get_date: fn(&T) -> (u8, u8, u16),
+    to_rata_die: fn(&T) -> RataDie,
+    add_months: fn(&T, i64, Overflow) -> Result<T, DateError>,
+) -> Result<DateSpan, DateError> {
+    let (start_day, start_month, start_year) = get_date(start);
+    let (end_day, end_month, end_year) = get_date(end);
+    let start_day_number = to_rata_die(start).get_day_number();
+    let end_day_number = to_rata_die(end).get_day_number();
+    let sign = if end_day_number < start_day_number {
+        -1
+    } else {
+        1
+    };
+
+    // the whole months, and one less if the day of the end is not reached yet
+    let mut months = sign
+        * ((end_year as i64 * 12 + end_month as i64)
+            - (start_year as i64 * 12 + start_month as i64));
+    if (sign > 0 && end_day < start_day) || (sign < 0 && end_day > start_day) {
+        months -= 1;
+    }
+
+    let mut anchor = add_months(start, sign * months, Overflow::Clamp)?;
+    while sign * (end_day_number - to_rata_die(&anchor).get_day_number()) < 0 {
+        months -= 1;
+        anchor = add_months(start, sign * months, Overflow::Clamp)?;
+    }
+
+    Ok(DateSpan {
+        total_days: end_day_number - start_day_number,
+        years: sign * (months / 12),
+        months: sign * (months % 12),
+        days: end_day_number - to_rata_die(&anchor).get_day_number(),
+    })
+}
+
+/// Move a month of a year by a number of months
+/// # Returns
+/// * `(u8, i64)` - The month and the year
+fn shift_month(month: u8, year: u16, months: i64) -> Result<(u8, i64), DateError> {
+    let index = (year as i64 * 12 + month as i64 - 1).checked_add(months);
+    match index {
+        Some(index) => Ok((index.rem_euclid(12) as u8 + 1, index.div_euclid(12))),
+        None => Err(DateError::ArithmeticError),
+    }
+}
+
+/// Fit a day into a month
+/// # Returns
+/// * `Option<(u8, i64)>` - The day in the month and the days carried into the next month
+/// # Note
+/// * The function will return `None` if the day does not exist and the policy is `Overflow::Reject`
+fn fit_day(day: u8, days_in_month: u8, overflow: Overflow) -> Option<(u8, i64)> {
+    if day <= days_in_month {
+        return Some((day, 0));
+    }
+
+    match overflow {
+        Overflow::Clamp => Some((days_in_month, 0)),
+        Overflow::Reject => None,
+        Overflow::RollOver => Some((days_in_month, (day - days_in_month) as i64)),
+    }
+}
+
+/// Multiply years into months
+fn years_to_months(years: i64) -> Result<i64, DateError> {
+    match years.checked_mul(12) {
+        Some(months) => Ok(months),
+        None => Err(DateError::ArithmeticError),
+    }
+}
+
+/// Negate a number of days, months or years
+fn negate(value: i64) -> Result<i64, DateError> {
+    match value.checked_neg() {
+        Some(value) => Ok(value),
+        None => Err(DateError::ArithmeticError),
+    }
+}
+
+/// Move a fixed day number by a number of days
+/// The new day is checked against the supported days of the calendar before it is converted
+fn shift_day(
+    rata_die: RataDie,
+    days: i64,
+    supported: RangeInclusive<i64>,
+) -> Result<RataDie, DateError> {
+    let rata_die = match rata_die.get_day_number().checked_add(days) {
+        Some(day_number) => RataDie::new(day_number),
+        None => return Err(DateError::ArithmeticError),
+    };
+    rata_die.check_range(supported)?;
+    Ok(rata_die)
+}
+
+impl EnglishDate {
+    /// Add days to the selected date
+    /// # Arguments
+    /// * `days` - i64, negative to go back
+    /// # Returns
+    /// * `EnglishDate` - The new date
+    /// # Example
+    /// ```
+    /// use ponjika::date::EnglishDate;
+    /// use ponjika::months::EnglishMonths;
+    /// let date = EnglishDate::create_date(31, EnglishMonths::December, 2023).unwrap();
+    /// assert_eq!(date.add_days(60).unwrap().get_date(), (29, 2, 2024));
+    /// ```
+    /// # Note
+    /// * The function will return `DateError::OutOfRange` with the `DateField::DayNumber` field if the new day is before or after the supported years
+    /// * The function will return `DateError::ArithmeticError` if the number of days overflows
+    pub fn add_days(&self, days: i64) -> Result<Self, DateError> {
+        EnglishDate::from_rata_die(shift_day(
+            self.to_rata_die(),
+            days,
+            rata_die::gregorian_days(),
+        )?)
+    }
+
+    /// Subtract days from the selected date
+    /// # Arguments
+    /// * `days` - i64
+    /// # Returns
+    /// * `EnglishDate` - The new date
+    /// # Example
+    /// ```
+    /// use ponjika::date::EnglishDate;
+    /// use ponjika::months::EnglishMonths;
+    /// let date = EnglishDate::create_date(1, EnglishMonths::March, 2024).unwrap();
+    /// assert_eq!(date.sub_days(1).unwrap().get_date(), (29, 2, 2024));
+    /// ```
+    /// # Note
+    /// * The function will return `DateError::OutOfRange` with the `DateField::DayNumber` field if the new day is before or after the supported years
+    /// * The function will return `DateError::ArithmeticError` if the number of days overflows
+    pub fn sub_days(&self, days: i64) -> Result<Self, DateError> {
+        self.add_days(negate(days)?)
+    }
+
+    /// Add months to the selected date
+    /// # Arguments
+    /// * `months` - i64, negative to go back
+    /// * `overflow` - Overflow
+    /// # Returns
+    /// * `EnglishDate` - The new date
+    /// # Example
+    /// ```
+    /// use ponjika::arithmetic::Overflow;
+    /// use ponjika::date::EnglishDate;
+    /// use ponjika::months::EnglishMonths;
+    /// let date = EnglishDate::create_date(31, EnglishMonths::January, 2024).unwrap();
+    /// assert_eq!(date.add_months(1, Overflow::Clamp).unwrap().get_date(), (29, 2, 2024));
+    /// assert_eq!(date.add_months(1, Overflow::RollOver).unwrap().get_date(), (2, 3, 2024));
+    /// assert!(date.add_months(1, Overflow::Reject).is_err());
+    /// ```
+    /// # Note
+    /// * The function will return `DateError::DayOutOfMonth` if the day does not exist and the policy is `Overflow::Reject`
+    /// * The function will return `DateError::OutOfRange` if the year of the new date is out of the supported range
+    /// * The function will return `DateError::ArithmeticError` if the number of days overflows
+    pub fn add_months(&self, months: i64, overflow: Overflow) -> Result<Self, DateError> {
+        let (day, month, year) = self.get_date();
+        let (month, year) = shift_month(month, year, months)?;
+        let year: u16 = match year.try_into() {
+            Ok(year) => year,
+            Err(_) => return Err(DateError::out_of_range(DateField::Year, year, 593, 9999)),
+        };
+
+        let month = match EnglishMonths::get_month(month) {
+            Ok(month) => month,
+            Err(err) => return Err(DateError::WrongMonth(err)),
+        };
+        let days_in_month = month.days_in(year);
+        match fit_day(day, days_in_month, overflow) {
+            Some((day, carry)) => EnglishDate::create_date(day, month, year)?.add_days(carry),
+            None => Err(DateError::DayOutOfMonth {
+                day,
+                month: Month::English(month),
+                year,
+                days: days_in_month,
+            }),
+        }
+    }
+
+    /// Subtract months from the selected date
+    /// # Arguments
+    /// * `months` - i64
+    /// * `overflow` - Overflow
+    /// # Returns
+    /// * `EnglishDate` - The new date
+    /// # Example
+    /// ```
+    /// use ponjika::arithmetic::Overflow;
+    /// use ponjika::date::EnglishDate;
+    /// use ponjika::months::EnglishMonths;
+    /// let date = EnglishDate::create_date(31, EnglishMonths::March, 2023).unwrap();
+    /// assert_eq!(date.sub_months(1, Overflow::Clamp).unwrap().get_date(), (28, 2, 2023));
+    /// ```
+    /// # Note
+    /// * The function will return `DateError::DayOutOfMonth` if the day does not exist and the policy is `Overflow::Reject`
+    /// * The function will return `DateError::OutOfRange` if the year of the new date is out of the supported range
+    pub fn sub_months(&self, months: i64, overflow: Overflow) -> Result<Self, DateError> {
+        self.add_months(negate(months)?, overflow)
+    }
+
+    /// Add years to the selected date
+    /// # Arguments
+    /// * `years` - i64, negative to go back
+    /// * `overflow` - Overflow
+    /// # Returns
+    /// * `EnglishDate` - The new date
+    /// # Example
+    /// ```
+    /// use ponjika::arithmetic::Overflow;
+    /// use ponjika::date::EnglishDate;
+    /// use ponjika::months::EnglishMonths;
+    /// let date = EnglishDate::create_date(29, EnglishMonths::February, 2024).unwrap();
+    /// assert_eq!(date.add_years(1, Overflow::Clamp).unwrap().get_date(), (28, 2, 2025));
+    /// assert_eq!(date.add_years(4, Overflow::Reject).unwrap().get_date(), (29, 2, 2028));
+    /// ```
+    /// # Note
+    /// * The function will return `DateError::DayOutOfMonth` if the day does not exist and the policy is `Overflow::Reject`
+    /// * The function will return `DateError::OutOfRange` if the year of the new date is out of the supported range
+    pub fn add_years(&self, years: i64, overflow: Overflow) -> Result<Self, DateError> {
+        self.add_months(years_to_months(years)?, overflow)
+    }
+
+    /// Subtract years from the selected date
+    /// # Arguments
+    /// * `years` - i64
+    /// * `overflow` - Overflow
+    /// # Returns
+    /// * `EnglishDate` - The new date
+    /// # Example
+    /// ```
+    /// use ponjika::arithmetic::Overflow;
+    /// use ponjika::date::EnglishDate;
+    /// use ponjika::months::EnglishMonths;
+    /// let date = EnglishDate::create_date(29, EnglishMonths::February, 2024).unwrap();
+    /// assert_eq!(date.sub_years(1, Overflow::RollOver).unwrap().get_date(), (1, 3, 2023));
+    /// ```
+    /// # Note
+    /// * The function will return `DateError::DayOutOfMonth` if the day does not exist and the policy is `Overflow::Reject`
+    /// * The function will return `DateError::OutOfRange` if the year of the new date is out of the supported range
+    pub fn sub_years(&self, years: i64, overflow: Overflow) -> Result<Self, DateError> {
+        self.add_months(negate(years_to_months(years)?)?, overflow)
+    }
+
+    /// Get the span from the selected date to another date
+    /// # Arguments
+    /// * `other` - &EnglishDate
+    /// # Returns
+    /// * `DateSpan` - The span, negative if the other date is earlier
+    /// # Example
+    /// ```
+    /// use ponjika::date::EnglishDate;
+    /// use ponjika::months::EnglishMonths;
+    /// let start = EnglishDate::create_date(31, EnglishMonths::January, 2024).unwrap();
+    /// let end = EnglishDate::create_date(1, EnglishMonths::March, 2025).unwrap();
+    /// let span = start.until(&end).unwrap();
+    /// assert_eq!(span.get_total_days(), 395);
+    /// assert_eq!(span.get_parts(), (1, 1, 1));
+    /// assert_eq!(end.until(&start).unwrap().get_parts(), (-1, -1, -1));
+    /// ```
+    /// # Note
+    /// * The function will return `DateError::ArithmeticError` if the span cannot be measured
+    pub fn until(&self, other: &EnglishDate) -> Result<DateSpan, DateError> {
+        measure(
+            self,
+            other,
+            EnglishDate::get_date,
+            EnglishDate::to_rata_die,
+            EnglishDate::add_months,
+        )
+    }
+
+    /// Get the span between the selected date and another date, whichever comes first
+    /// # Arguments
+    /// * `other` - &EnglishDate
+    /// # Returns
+    /// * `DateSpan` - The span, never negative
+    /// # Example
+    /// ```
+    /// use ponjika::date::EnglishDate;
+    /// use ponjika::months::EnglishMonths;
+    /// let start = EnglishDate::create_date(14, EnglishMonths::April, 2024).unwrap();
+    /// let end = EnglishDate::create_date(1, EnglishMonths::January, 2024).unwrap();
+    /// assert_eq!(start.difference(&end).unwrap().get_total_days(), 104);
+    /// ```
+    /// # Note
+    /// * The function will return `DateError::ArithmeticError` if the span cannot be measured
+    pub fn difference(&self, other: &EnglishDate) -> Result<DateSpan, DateError> {
+        if other.to_rata_die() >= self.to_rata_die() {
+            self.until(other)
+        } else {
+            other.until(self)
+        }
+    }
+}
+
+impl BengaliDate {
+    /// Add days to the selected date
+    /// # Arguments
+    /// * `days` - i64, negative to go back
+    /// # Returns
+    /// * `BengaliDate` - The new date
+    /// # Example
+    /// ```
+    /// use ponjika::date::BengaliDate;
+    /// use ponjika::months::BengaliMonths;
+    /// let date = BengaliDate::create_date(15, BengaliMonths::Shrawan, 1431).unwrap();
+    /// assert_eq!(date.add_days(30).unwrap().get_date(), (14, 5, 1431));
+    /// ```
+    /// # Note
+    /// * The new date keeps the calendar variant of the selected date
+    /// * The function will return `DateError::OutOfRange` with the `DateField::DayNumber` field if the new day is before or after the supported years
+    /// * The function will return `DateError::ArithmeticError` if the number of days overflows
+    pub fn add_days(&self, days: i64) -> Result<Self, DateError> {
+        let variant = self.get_variant();
+        let rata_die = shift_day(self.to_rata_die(), days, calendar::bengali_days(variant))?;
+        BengaliDate::from_rata_die(rata_die, variant)
+    }
+
+    /// Subtract days from the selected date
+    /// # Arguments
+    /// * `days` - i64
+    /// # Returns
+    /// * `BengaliDate` - The new date
+    /// # Example
+    /// ```
+    /// use ponjika::date::BengaliDate;
+    /// use ponjika::months::BengaliMonths;
+    /// let date = BengaliDate::create_date(1, BengaliMonths::Baishakh, 1431).unwrap();
+    /// assert_eq!(date.sub_days(1).unwrap().get_date(), (30, 12, 1430));
+    /// ```
+    /// # Note
+    /// * The function will return `DateError::OutOfRange` with the `DateField::DayNumber` field if the new day is before or after the supported years
+    /// * The function will return `DateError::ArithmeticError` if the number of days overflows
+    pub fn sub_days(&self, days: i64) -> Result<Self, DateError> {
+        self.add_days(negate(days)?)
+    }
+
+    /// Add months to the selected date
+    /// # Arguments
+    /// * `months` - i64, negative to go back
+    /// * `overflow` - Overflow
+    /// # Returns
+    /// * `BengaliDate` - The new date
+    /// # Example
+    /// ```
+    /// use ponjika::arithmetic::Overflow;
+    /// use ponjika::calendar::CalendarVariant;
+    /// use ponjika::date::BengaliDate;
+    /// use ponjika::months::BengaliMonths;
+    /// let date = BengaliDate::create_date_with_variant(31, BengaliMonths::Bhadra, 1420, CalendarVariant::Revised1987).unwrap();
+    /// assert_eq!(date.add_months(1, Overflow::Clamp).unwrap().get_date(), (30, 6, 1420));
+    /// assert_eq!(date.add_months(1, Overflow::RollOver).unwrap().get_date(), (1, 7, 1420));
+    /// assert!(date.add_months(1, Overflow::Reject).is_err());
+    /// ```
+    /// # Note
+    /// * The new date keeps the calendar variant of the selected date
+    /// * The function will return `DateError::DayOutOfMonth` if the day does not exist and the policy is `Overflow::Reject`
+    /// * The function will return `DateError::OutOfRange` if the year of the new date is out of the supported range
+    /// * The function will return `DateError::ArithmeticError` if the number of days overflows
+    pub fn add_months(&self, months: i64, overflow: Overflow) -> Result<Self, DateError> {
+        let (day, month, year) = self.get_date();
+        let (month, year) = shift_month(month, year, months)?;
+        let year: u16 = match year.try_into() {
+            Ok(year) => year,
+            Err(_) => return Err(DateError::out_of_range(DateField::Year, year, 1, 9406)),
+        };
+
+        let variant = self.get_variant();
+        let days_in_month = match variant.days_in_month(month, year as i64) {
+            Some(days) => days,
+            None => return Err(DateError::ArithmeticError),
+        };
+        let month = match BengaliMonths::get_month(month) {
+            Ok(month) => month,
+            Err(err) => return Err(DateError::WrongMonth(err)),
+        };
+        match fit_day(day, days_in_month, overflow) {
+            Some((day, carry)) => {
+                BengaliDate::create_date_with_variant(day, month, year, variant)?.add_days(carry)
+            }
+            None => Err(DateError::DayOutOfMonth {
+                day,
+                month: Month::Bengali(month),
+                year,
+                days: days_in_month,
+            }),
+        }
+    }
+
+    /// Subtract months from the selected date
+    /// # Arguments
+    /// * `months` - i64
+    /// * `overflow` - Overflow
+    /// # Returns
+    /// * `BengaliDate` - The new date
+    /// # Example
+    /// ```
+    /// use ponjika::arithmetic::Overflow;
+    /// use ponjika::date::BengaliDate;
+    /// use ponjika::months::BengaliMonths;
+    /// let date = BengaliDate::create_date(10, BengaliMonths::Baishakh, 1431).unwrap();
+    /// assert_eq!(date.sub_months(1, Overflow::Reject).unwrap().get_date(), (10, 12, 1430));
+    /// ```
+    /// # Note
+    /// * The function will return `DateError::DayOutOfMonth` if the day does not exist and the policy is `Overflow::Reject`
+    /// * The function will return `DateError::OutOfRange` if the year of the new date is out of the supported range
+    pub fn sub_months(&self, months: i64, overflow: Overflow) -> Result<Self, DateError> {
+        self.add_months(negate(months)?, overflow)
+    }
+
+    /// Add years to the selected date
+    /// # Arguments
+    /// * `years` - i64, negative to go back
+    /// * `overflow` - Overflow
+    /// # Returns
+    /// * `BengaliDate` - The new date
+    /// # Example
+    /// ```
+    /// use ponjika::arithmetic::Overflow;
+    /// use ponjika::date::BengaliDate;
+    /// use ponjika::months::BengaliMonths;
+    /// // Falgun 1430 has a leap day, Falgun 1431 does not
+    /// let date = BengaliDate::create_date(30, BengaliMonths::Falgun, 1430).unwrap();
+    /// assert_eq!(date.add_years(1, Overflow::Clamp).unwrap().get_date(), (29, 11, 1431));
+    /// ```
+    /// # Note
+    /// * The function will return `DateError::DayOutOfMonth` if the day does not exist and the policy is `Overflow::Reject`
+    /// * The function will return `DateError::OutOfRange` if the year of the new date is out of the supported range
+    pub fn add_years(&self, years: i64, overflow: Overflow) -> Result<Self, DateError> {
+        self.add_months(years_to_months(years)?, overflow)
+    }
+
+    /// Subtract years from the selected date
+    /// # Arguments
+    /// * `years` - i64
+    /// * `overflow` - Overflow
+    /// # Returns
+    /// * `BengaliDate` - The new date
+    /// # Example
+    /// ```
+    /// use ponjika::arithmetic::Overflow;
+    /// use ponjika::date::BengaliDate;
+    /// use ponjika::months::BengaliMonths;
+    /// let date = BengaliDate::create_date(1, BengaliMonths::Baishakh, 1431).unwrap();
+    /// assert_eq!(date.sub_years(31, Overflow::Reject).unwrap().get_date(), (1, 1, 1400));
+    /// ```
+    /// # Note
+    /// * The function will return `DateError::DayOutOfMonth` if the day does not exist and the policy is `Overflow::Reject`
+    /// * The function will return `DateError::OutOfRange` if the year of the new date is out of the supported range
+    pub fn sub_years(&self, years: i64, overflow: Overflow) -> Result<Self, DateError> {
+        self.add_months(negate(years_to_months(years)?)?, overflow)
+    }
+
+    /// Get the span from the selected date to another date
+    /// # Arguments
+    /// * `other` - &BengaliDate
+    /// # Returns
+    /// * `DateSpan` - The span, negative if the other date is earlier
+    /// # Example
+    /// ```
+    /// use ponjika::date::BengaliDate;
+    /// use ponjika::months::BengaliMonths;
+    /// let today = BengaliDate::create_date(20, BengaliMonths::Chaitra, 1430).unwrap();
+    /// let new_year = BengaliDate::create_date(1, BengaliMonths::Baishakh, 1431).unwrap();
+    /// let span = today.until(&new_year).unwrap();
+    /// assert_eq!(span.get_total_days(), 11);
+    /// assert_eq!(span.get_parts(), (0, 0, 11));
+    /// ```
+    /// # Note
+    /// * The years, months and days are counted in the calendar variant of the selected date
+    /// * The function will return `DateError::OutOfRange` if the other date cannot be written in that variant
+    pub fn until(&self, other: &BengaliDate) -> Result<DateSpan, DateError> {
+        if other.get_variant() != self.get_variant() {
+            let other = BengaliDate::from_rata_die(other.to_rata_die(), self.get_variant())?;
+            return self.until(&other);
+        }
+
+        measure(
+            self,
+            other,
+            BengaliDate::get_date,
+            BengaliDate::to_rata_die,
+            BengaliDate::add_months,
+        )
+    }
+
+    /// Get the span between the selected date and another date, whichever comes first
+    /// # Arguments
+    /// * `other` - &BengaliDate
+    /// # Returns
+    /// * `DateSpan` - The span, never negative
+    /// # Example
+    /// ```
+    /// use ponjika::date::BengaliDate;
+    /// use ponjika::months::BengaliMonths;
+    /// let start = BengaliDate::create_date(1, BengaliMonths::Baishakh, 1431).unwrap();
+    /// let end = BengaliDate::create_date(1, BengaliMonths::Baishakh, 1430).unwrap();
+    /// assert_eq!(start.difference(&end).unwrap().get_parts(), (1, 0, 0));
+    /// ```
+    /// # Note
+    /// * The years, months and days are counted in the calendar variant of the earlier date
+    /// * The function will return `DateError::OutOfRange` if the later date cannot be written in that variant
+    pub fn difference(&self, other: &BengaliDate) -> Result<DateSpan, DateError> {
+        if other.to_rata_die() >= self.to_rata_die() {
+            self.until(other)
+        } else {
+            other.until(self)
+        }
+    }
+}
+
+impl Add<Days> for EnglishDate {
+    type Output = Result<EnglishDate, DateError>;
+
+    /// Add a duration to the date
+    /// # Example
+    /// ```
+    /// use ponjika::arithmetic::Days;
+    /// use ponjika::date::EnglishDate;
+    /// use ponjika::months::EnglishMonths;
+    /// let date = EnglishDate::create_date(1, EnglishMonths::January, 2024).unwrap();
+    /// assert_eq!((date + Days::new(31)).unwrap().get_date(), (1, 2, 2024));
+    /// ```
+    fn add(self, days: Days) -> Self::Output {
+        self.add_days(days.get_days())
+    }
+}
+
+impl Sub<Days> for EnglishDate {
+    type Output = Result<EnglishDate, DateError>;
+
+    /// Subtract a duration from the date
+    /// # Example
+    /// ```
+    /// use ponjika::arithmetic::Days;
+    /// use ponjika::date::EnglishDate;
+    /// use ponjika::months::EnglishMonths;
+    /// let date = EnglishDate::create_date(1, EnglishMonths::January, 2024).unwrap();
+    /// assert_eq!((date - Days::new(1)).unwrap().get_date(), (31, 12, 2023));
+    /// ```
+    fn sub(self, days: Days) -> Self::Output {
+        self.sub_days(days.get_days())
+    }
+}
+
+impl Add<Days> for BengaliDate {
+    type Output = Result<BengaliDate, DateError>;
+
+    /// Add a duration to the date
+    /// # Example
+    /// ```
+    /// use ponjika::arithmetic::Days;
+    /// use ponjika::date::BengaliDate;
+    /// use ponjika::months::BengaliMonths;
+    /// let date = BengaliDate::create_date(15, BengaliMonths::Shrawan, 1431).unwrap();
+    /// assert_eq!((date + Days::new(30)).unwrap().get_date(), (14, 5, 1431));
+    /// ```
+    fn add(self, days: Days) -> Self::Output {
+        self.add_days(days.get_days())
+    }
+}
+
+impl Sub<Days> for BengaliDate {
+    type Output = Result<BengaliDate, DateError>;
+
+    /// Subtract a duration from the date
+    /// # Example
+    /// ```
+    /// use ponjika::arithmetic::Days;
+    /// use ponjika::date::BengaliDate;
+    /// use ponjika::months::BengaliMonths;
+    /// let date = BengaliDate::create_date(1, BengaliMonths::Baishakh, 1431).unwrap();
+    /// assert_eq!((date - Days::new(1)).unwrap().get_date(), (30, 12, 1430));
+    /// ```
+    fn sub(self, days: Days) -> Self::Output {
+        self.sub_days(days.get_days())
+    }
+}
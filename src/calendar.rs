@@ -1,137 +1,359 @@
 //! # Calendar: Calendar related common functions
 //! The module contains common functions related to calendar
 //! The functions are used to format the date in Bengali and English
+//! The `CalendarVariant` enum is used to select the rule set of the Bengali calendar.
+//! Every conversion goes through the fixed day number of the `rata_die` module.
+//! The `DayBoundary` enum selects whether a Bengali day starts at midnight or at sunrise.
+//! The current date is read from a `Clock` of the `clock` module.
+//! The `Switchover` struct selects the day the Julian calendar was replaced by the Gregorian calendar.
 
-use chrono::{Datelike, Local};
+use chrono::{DateTime, Datelike, Local, TimeZone, Timelike};
 use std::convert::TryInto;
+use std::ops::RangeInclusive;
 
-use crate::{
-    BengaliDate, BengaliMonths, BengaliWeekDays, Date, DateError, EnglishDate, EnglishMonths,
-};
+use crate::clock::{BengaliTimeZone, Clock, SystemClock};
+use crate::rata_die::{self, RataDie};
+use crate::siddhanta;
+use crate::sun;
+use crate::{BengaliDate, Date, DateError, DateField, EnglishDate, EnglishMonths, JulianDate};
 
-fn is_leap_year(year: u16) -> bool {
-    year % 4 == 0
+/// # `CalendarVariant`: The rule set of the Bengali calendar.
+/// Bangladesh changed the rules of the national calendar several times. The traditional calendar follows the sun,
+/// the 1966 reform gave the months fixed lengths, the 1987 rules tied the leap day to the Gregorian leap year,
+/// and the 2019 revision distributed the days of the year differently among the months.
+/// The traditional calendar is still used in West Bengal.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CalendarVariant {
+    /// The Bangla Academy calendar recommended in 1966 by the committee led by Muhammad Shahidullah.
+    /// The first five months have 31 days, Falgun has 30 days (31 when the Bengali year is divisible by 4)
+    /// and the other months have 30 days.
+    Reformed1966,
+    /// The Bangla Academy calendar used before the 2019 revision.
+    /// The first five months have 31 days, Falgun has 30 days (31 in leap years) and the other months have 30 days.
+    Revised1987,
+    /// The Bangla Academy calendar revised in 2019.
+    /// The first six months have 31 days, Falgun has 29 days (30 in leap years) and the other months have 30 days.
+    #[default]
+    Revised2019,
+    /// The traditional calendar used in West Bengal, computed with the Surya Siddhanta.
+    /// A month starts on the day after the sun enters its sidereal zodiac sign, so months have 29 to 32 days
+    /// and 1 Baishakh usually falls on 14th or 15th April.
+    Traditional,
+    /// The rule set in effect in Bangladesh on the date.
+    /// The traditional calendar until 1372, the 1966 reform from 1373, the 1987 rules from 1394,
+    /// and the 2019 revision from 1426.
+    Historical,
 }
 
-/// converts Gregorian date to Bengali date
-/// # Arguments
-/// * `english_date` - EnglishDate
-/// # Returns
-/// * `Date` - Bengali date
-/// # Note
-/// * The function will return `DateError` if the conversion fails
-fn gregorian_to_bengali_date(english_date: EnglishDate) -> Result<Date, DateError> {
-    let (english_day, english_month, english_year) = english_date.get_date();
-
-    // The Bengali year starts from 14th April
-    // If the English date is before 14th April, the Bengali year is the English year - 594
-    // Otherwise, the Bengali year is the English year - 593
-    let bengali_year: u16 = if english_month < 4 || (english_month == 4 && english_day < 14) {
-        match english_year.checked_sub(594) {
-            Some(year) => year,
-            None => {
-                return Err(DateError::ArithmeticError);
-            }
+impl CalendarVariant {
+    /// Get the rule set in effect in Bangladesh for a Bengali year
+    /// # Arguments
+    /// * `year` - u16
+    /// # Returns
+    /// * `CalendarVariant` - The rule set of the year
+    /// # Example
+    /// ```
+    /// use ponjika::calendar::CalendarVariant;
+    /// assert_eq!(CalendarVariant::for_year(1350), CalendarVariant::Traditional);
+    /// assert_eq!(CalendarVariant::for_year(1380), CalendarVariant::Reformed1966);
+    /// assert_eq!(CalendarVariant::for_year(1404), CalendarVariant::Revised1987);
+    /// assert_eq!(CalendarVariant::for_year(1431), CalendarVariant::Revised2019);
+    /// ```
+    /// # Note
+    /// * Every year starts on 14th April at the change of the rules, so the epochs do not overlap
+    pub fn for_year(year: u16) -> CalendarVariant {
+        match year {
+            0..=1372 => CalendarVariant::Traditional,
+            1373..=1393 => CalendarVariant::Reformed1966,
+            1394..=1425 => CalendarVariant::Revised1987,
+            _ => CalendarVariant::Revised2019,
         }
-    } else {
-        match english_year.checked_sub(593) {
-            Some(year) => year,
-            None => {
-                return Err(DateError::ArithmeticError);
+    }
+
+    /// The rule set used for a Bengali year of the variant
+    fn resolve(&self, year: i64) -> CalendarVariant {
+        match self {
+            CalendarVariant::Historical => {
+                CalendarVariant::for_year(year.clamp(0, u16::MAX as i64) as u16)
             }
+            _ => *self,
         }
-    };
+    }
 
-    // get the Bengali day and month
-    let (bengali_day, bengali_month) = if english_month == 1 {
-        if english_day <= 14 {
-            (english_day + 16, 9)
-        } else {
-            (english_day - 14, 10)
+    /// The number of days of a Bengali month in a year of the variant
+    /// The length of Falgun is decided by the Gregorian year that the month falls in
+    pub(crate) fn days_in_month(&self, month: u8, year: i64) -> Option<u8> {
+        match month {
+            1..=12 => Some(self.resolve(year).month_length(month, year)),
+            _ => None,
         }
-    } else if english_month == 2 {
-        if english_day <= 13 {
-            (english_day + 17, 10)
-        } else {
-            (english_day - 13, 11)
+    }
+
+    fn month_length(&self, month: u8, year: i64) -> u8 {
+        // Falgun falls in February of the next Gregorian year
+        let leap_day = is_leap_year(year + 594) as u8;
+        match (self, month) {
+            (CalendarVariant::Traditional, _) => siddhanta::days_in_month(year, month),
+            (CalendarVariant::Reformed1966, 1..=5) => 31,
+            (CalendarVariant::Reformed1966, 11) => 30 + (year % 4 == 0) as u8,
+            (CalendarVariant::Revised1987, 1..=5) => 31,
+            (CalendarVariant::Revised1987, 11) => 30 + leap_day,
+            (CalendarVariant::Revised2019, 1..=6) => 31,
+            (CalendarVariant::Revised2019, 11) => 29 + leap_day,
+            _ => 30,
         }
-    } else if english_month == 3 {
-        if english_day <= 14 {
-            if is_leap_year(english_year) {
-                (english_day + 16, 11)
-            } else {
-                (english_day + 15, 11)
+    }
+
+    /// The fixed day number of 1 Baishakh of a year with fixed month lengths
+    fn new_year(&self, year: i64) -> i64 {
+        match self {
+            // The reform started on 14th April 1966 and counts its own leap years from there
+            CalendarVariant::Reformed1966 => {
+                rata_die::fixed_from_gregorian(14, 4, 1966)
+                    + 365 * (year - 1373)
+                    + (year - 1).div_euclid(4)
+                    - 1372 / 4
             }
-        } else {
-            (english_day - 14, 12)
-        }
-    } else if english_month == 4 {
-        if english_day <= 13 {
-            (english_day + 17, 12)
-        } else {
-            (english_day - 13, 1)
-        }
-    } else if english_month == 5 {
-        if english_day <= 14 {
-            (english_day + 17, 1)
-        } else {
-            (english_day - 14, 2)
-        }
-    } else if english_month == 6 {
-        if english_day <= 14 {
-            (english_day + 17, 2)
-        } else {
-            (english_day - 14, 3)
-        }
-    } else if english_month == 7 {
-        if english_day <= 15 {
-            (english_day + 16, 3)
-        } else {
-            (english_day - 15, 4)
-        }
-    } else if english_month == 8 {
-        if english_day <= 15 {
-            (english_day + 16, 4)
-        } else {
-            (english_day - 15, 5)
+            _ => rata_die::fixed_from_gregorian(14, 4, year + 593),
         }
-    } else if english_month == 9 {
-        if english_day <= 15 {
-            (english_day + 16, 5)
-        } else {
-            (english_day - 15, 6)
+    }
+}
+
+/// # `DayBoundary`: The moment a Bengali day starts.
+/// The civil calendar of Bangladesh starts the day at midnight, like the Gregorian calendar.
+/// The traditional ponjika starts the day at sunrise, so the early hours belong to the previous day.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DayBoundary {
+    /// The day starts at midnight in the time zone of the moment.
+    #[default]
+    Midnight,
+    /// The day starts at the local sunrise.
+    Sunrise,
+}
+
+/// # `Switchover`: The day the Gregorian calendar replaced the Julian calendar.
+/// English dates before the first Gregorian day are written in the Julian calendar.
+/// The default is 15th October 1582, when the reform took effect in the Catholic countries.
+/// Britain and its colonies, Bengal included, switched on 14th September 1752.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Switchover {
+    first_gregorian_day: RataDie,
+}
+
+impl Default for Switchover {
+    fn default() -> Self {
+        Switchover {
+            first_gregorian_day: RataDie::new(rata_die::fixed_from_gregorian(15, 10, 1582)),
         }
-    } else if english_month == 10 {
-        if english_day <= 16 {
-            (english_day + 15, 6)
-        } else {
-            (english_day - 16, 7)
+    }
+}
+
+impl Switchover {
+    /// Create a switchover
+    /// # Arguments
+    /// * `first_gregorian_day` - EnglishDate
+    /// # Returns
+    /// * `Switchover` - The switchover on the given day
+    /// # Example
+    /// ```
+    /// use ponjika::calendar::Switchover;
+    /// use ponjika::date::EnglishDate;
+    /// use ponjika::months::EnglishMonths;
+    /// let british = Switchover::new(EnglishDate::create_date(14, EnglishMonths::September, 1752).unwrap());
+    /// assert_ne!(british, Switchover::default());
+    /// ```
+    pub fn new(first_gregorian_day: EnglishDate) -> Self {
+        Switchover {
+            first_gregorian_day: first_gregorian_day.to_rata_die(),
         }
-    } else if english_month == 11 {
-        if english_day <= 15 {
-            (english_day + 15, 7)
-        } else {
-            (english_day - 15, 8)
+    }
+
+    /// Get the first day of the Gregorian calendar
+    /// # Returns
+    /// * `RataDie` - The fixed day number of the first Gregorian day
+    /// # Example
+    /// ```
+    /// use ponjika::calendar::Switchover;
+    /// assert_eq!(Switchover::default().get_first_gregorian_day().get_day_number(), 577_736);
+    /// ```
+    pub fn get_first_gregorian_day(&self) -> RataDie {
+        self.first_gregorian_day
+    }
+
+    /// Create an English date in the calendar in use on the day
+    /// # Arguments
+    /// * `day` - u8
+    /// * `month` - EnglishMonths
+    /// * `year` - u16
+    /// # Returns
+    /// * `Result<Date, DateError>` - `Date::Julian` before the switchover, `Date::English` from it
+    /// # Example
+    /// ```
+    /// use ponjika::calendar::Switchover;
+    /// use ponjika::date::Date;
+    /// use ponjika::months::EnglishMonths;
+    /// let switchover = Switchover::default();
+    /// let date = switchover.create_date(4, EnglishMonths::October, 1582).unwrap();
+    /// assert!(matches!(date, Date::Julian(_)));
+    /// let date = switchover.create_date(15, EnglishMonths::October, 1582).unwrap();
+    /// assert!(matches!(date, Date::English(_)));
+    /// assert!(switchover.create_date(10, EnglishMonths::October, 1582).is_err());
+    /// ```
+    /// # Note
+    /// * The function will return `DateError::SkippedDay` for the days skipped by the switchover
+    /// * The function will return `DateError::OutOfRange` or `DateError::DayOutOfMonth` if the date does not exist in either calendar
+    pub fn create_date(&self, day: u8, month: EnglishMonths, year: u16) -> Result<Date, DateError> {
+        let julian_valid = match JulianDate::create_date(day, month, year) {
+            Ok(date) if date.to_rata_die() < self.first_gregorian_day => {
+                return Ok(Date::Julian(date))
+            }
+            Ok(_) => true,
+            Err(_) => false,
+        };
+
+        match EnglishDate::create_date(day, month, year) {
+            Ok(date) if date.to_rata_die() >= self.first_gregorian_day => Ok(Date::English(date)),
+            // the day was skipped when the calendars were switched
+            Ok(_) => Err(DateError::SkippedDay { day, month, year }),
+            Err(_) if julian_valid => Err(DateError::SkippedDay { day, month, year }),
+            Err(err) => Err(err),
         }
-    } else if english_month == 12 {
-        if english_day <= 15 {
-            (english_day + 15, 8)
+    }
+
+    /// Create an English date from a fixed day number in the calendar in use on the day
+    /// # Arguments
+    /// * `rata_die` - RataDie
+    /// # Returns
+    /// * `Result<Date, DateError>` - `Date::Julian` before the switchover, `Date::English` from it
+    /// # Example
+    /// ```
+    /// use ponjika::calendar::Switchover;
+    /// use ponjika::rata_die::RataDie;
+    /// let date = Switchover::default().from_rata_die(RataDie::new(577_735)).unwrap();
+    /// assert_eq!(date.to_string(), "Thursday, 4 October 1582");
+    /// let date = Switchover::default().from_rata_die(RataDie::new(577_736)).unwrap();
+    /// assert_eq!(date.to_string(), "Friday, 15 October 1582");
+    /// ```
+    /// # Note
+    /// * The function will return `DateError::OutOfRange` if the year of the date is out of the supported range
+    pub fn from_rata_die(&self, rata_die: RataDie) -> Result<Date, DateError> {
+        if rata_die < self.first_gregorian_day {
+            JulianDate::from_rata_die(rata_die).map(Date::Julian)
         } else {
-            (english_day - 15, 9)
+            EnglishDate::from_rata_die(rata_die).map(Date::English)
         }
+    }
+}
+
+/// Check whether a year of the proleptic Gregorian calendar is a leap year
+/// # Arguments
+/// * `year` - i64
+/// # Returns
+/// * `bool` - `true` if February of the year has 29 days
+/// # Note
+/// * A year divisible by 100 is a leap year only if it is also divisible by 400
+pub(crate) fn is_leap_year(year: i64) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+/// Check whether a year of the Julian calendar is a leap year
+/// # Arguments
+/// * `year` - i64
+/// # Returns
+/// * `bool` - `true` if February of the year has 29 days
+/// # Note
+/// * Every year divisible by 4 is a leap year, including the century years
+pub(crate) fn is_julian_leap_year(year: i64) -> bool {
+    year.rem_euclid(4) == 0
+}
+
+/// Get the fixed day number of a Bengali date
+/// # Arguments
+/// * `day` - u8
+/// * `month` - u8
+/// * `year` - i64
+/// * `variant` - CalendarVariant
+/// # Returns
+/// * `i64` - The fixed day number
+/// # Note
+/// * The Bangladesh calendar starts the year on 14th April
+pub(crate) fn fixed_from_bengali(day: u8, month: u8, year: i64, variant: CalendarVariant) -> i64 {
+    let variant = variant.resolve(year);
+    if variant == CalendarVariant::Traditional {
+        return siddhanta::to_fixed(day, month, year);
+    }
+
+    let new_year = variant.new_year(year);
+    let elapsed_months: i64 = (1..month)
+        .map(|month| variant.month_length(month, year) as i64)
+        .sum();
+
+    new_year + elapsed_months + day as i64 - 1
+}
+
+/// Get the fixed day numbers of the supported Bengali days
+/// # Arguments
+/// * `variant` - CalendarVariant
+/// # Returns
+/// * `RangeInclusive<i64>` - The days from 1 Baishakh 1 to the last day of Chaitra 9406
+pub(crate) fn bengali_days(variant: CalendarVariant) -> RangeInclusive<i64> {
+    fixed_from_bengali(1, 1, 1, variant)..=fixed_from_bengali(1, 1, 9407, variant) - 1
+}
+
+/// Get the Bengali date of a fixed day number
+/// # Arguments
+/// * `fixed` - i64
+/// * `variant` - CalendarVariant
+/// # Returns
+/// * `(u8, u8, i64)` - The day, month, and year
+pub(crate) fn bengali_from_fixed(fixed: i64, variant: CalendarVariant) -> (u8, u8, i64) {
+    // The Bengali year starts from 14th April
+    // If the English date is before 14th April, the Bengali year is the English year - 594
+    // Otherwise, the Bengali year is the English year - 593
+    let (_, _, english_year) = rata_die::gregorian_from_fixed(fixed);
+    let mut year = if fixed < rata_die::fixed_from_gregorian(14, 4, english_year) {
+        english_year - 594
     } else {
-        return Err(DateError::WrongDay);
+        english_year - 593
     };
 
-    let bengali_weekday =
-        BengaliWeekDays::get_english_weekday(english_date.get_week_day().unwrap().as_str());
+    // the rules changed on 14th April, so the year above decides the historical rule set
+    let variant = variant.resolve(year);
+    if variant == CalendarVariant::Traditional {
+        return siddhanta::from_fixed(fixed);
+    }
+
+    // the leap days of the 1966 reform can move the new year away from 14th April
+    while fixed < variant.new_year(year) {
+        year -= 1;
+    }
+    while fixed >= variant.new_year(year + 1) {
+        year += 1;
+    }
+
+    let mut days = fixed - variant.new_year(year);
+    let mut month = 1;
+    while month < 12 && days >= variant.month_length(month, year) as i64 {
+        days -= variant.month_length(month, year) as i64;
+        month += 1;
+    }
+
+    (days as u8 + 1, month, year)
+}
 
-    match BengaliDate::create_date_with_weekday(
-        bengali_day,
-        bengali_weekday.unwrap(),
-        BengaliMonths::get_month(bengali_month).unwrap(),
-        bengali_year,
-    ) {
+/// converts Gregorian date to Bengali date
+/// # Arguments
+/// * `english_date` - EnglishDate
+/// * `variant` - CalendarVariant
+/// # Returns
+/// * `Date` - Bengali date
+/// # Note
+/// * The function will return `DateError::OutOfRange` if the year of the converted date is out of the supported range
+fn gregorian_to_bengali_date(
+    english_date: EnglishDate,
+    variant: CalendarVariant,
+) -> Result<Date, DateError> {
+    match BengaliDate::from_rata_die(english_date.to_rata_die(), variant) {
         Ok(bengali_date) => Ok(Date::Bengali(bengali_date)),
         Err(err) => Err(err),
     }
@@ -143,159 +365,50 @@ fn gregorian_to_bengali_date(english_date: EnglishDate) -> Result<Date, DateErro
 /// # Returns
 /// * `Date` - Gregorian date
 /// # Note
-/// * The function will return `DateError` if the conversion fails
+/// * The function will use the calendar variant of the Bengali date
+/// * The function will return `DateError::OutOfRange` if the year of the converted date is out of the supported range
 fn bengali_to_gregorian_date(bengali_date: BengaliDate) -> Result<Date, DateError> {
-    let (bengali_day, bengali_month, bengali_year) = bengali_date.get_date();
-
-    let english_year: u16 = if (bengali_month == 9 && bengali_day >= 17)
-        || (bengali_month == 12 && bengali_day <= 30)
-        || bengali_month == 10
-        || bengali_month == 11
-    {
-        match bengali_year.checked_add(594) {
-            Some(year) => year,
-            None => return Err(DateError::ArithmeticError),
-        }
-    } else {
-        match bengali_year.checked_add(593) {
-            Some(year) => year,
-            None => return Err(DateError::ArithmeticError),
-        }
-    };
-
-    let (english_date, english_month) = if bengali_month == 9 {
-        if bengali_day >= 17 {
-            (bengali_day - 16, 1)
-        } else {
-            (bengali_day + 15, 12)
-        }
-    } else if bengali_month == 10 {
-        if bengali_day >= 18 {
-            (bengali_day - 17, 2)
-        } else {
-            (bengali_day + 14, 1)
-        }
-    } else if bengali_month == 11 {
-        if is_leap_year(english_year) {
-            if bengali_day >= 17 {
-                (bengali_day - 16, 3)
-            } else {
-                (bengali_day + 13, 2)
-            }
-        } else {
-            if bengali_day >= 16 {
-                (bengali_day - 15, 3)
-            } else {
-                (bengali_day + 13, 2)
-            }
-        }
-    } else if bengali_month == 12 {
-        if bengali_day >= 18 {
-            (bengali_day - 17, 4)
-        } else {
-            (bengali_day + 14, 3)
-        }
-    } else if bengali_month == 1 {
-        if bengali_day >= 18 {
-            (bengali_day - 17, 5)
-        } else {
-            (bengali_day + 13, 4)
-        }
-    } else if bengali_month == 2 {
-        if bengali_day >= 18 {
-            (bengali_day - 17, 6)
-        } else {
-            (bengali_day + 14, 5)
-        }
-    } else if bengali_month == 3 {
-        if bengali_day >= 17 {
-            (bengali_day - 16, 7)
-        } else {
-            (bengali_day + 14, 6)
-        }
-    } else if bengali_month == 4 {
-        if bengali_day >= 17 {
-            (bengali_day - 16, 8)
-        } else {
-            (bengali_day + 15, 7)
-        }
-    } else if bengali_month == 5 {
-        if bengali_day >= 17 {
-            (bengali_day - 16, 9)
-        } else {
-            (bengali_day + 15, 8)
-        }
-    } else if bengali_month == 6 {
-        if bengali_day >= 16 {
-            (bengali_day - 15, 10)
-        } else {
-            (bengali_day + 15, 9)
-        }
-    } else if bengali_month == 7 {
-        if bengali_day >= 16 {
-            (bengali_day - 15, 11)
-        } else {
-            (bengali_day + 16, 10)
-        }
-    } else if bengali_month == 8 {
-        if bengali_day >= 16 {
-            (bengali_day - 15, 12)
-        } else {
-            (bengali_day + 15, 11)
-        }
-    } else {
-        return Err(DateError::WrongDay);
-    };
-
-    match EnglishDate::create_date(
-        english_date,
-        EnglishMonths::get_month(english_month).unwrap(),
-        english_year,
-    ) {
+    match EnglishDate::from_rata_die(bengali_date.to_rata_die()) {
         Ok(english_date) => Ok(Date::English(english_date)),
         Err(err) => Err(err),
     }
 }
 
-/// Get today's Bengali date
+/// converts the day of a moment in a time zone to Bengali date
+/// # Arguments
+/// * `moment` - DateTime<Tz>
+/// * `variant` - CalendarVariant
 /// # Returns
-/// * `Result<Date, DateError>` - Bengali date
-/// # Example
-/// ```
-/// use ponjika::calendar;
-/// let today = calendar::get_today_bengali_date();
-/// match today {
-///   Ok(bengali_date) => {
-///     println!("{}", bengali_date.to_string());
-///   }
-///   Err(_) => {
-///     eprintln!("The date is not a valid greogrian date");
-///   }
-/// }
-/// ```
+/// * `Date` - Bengali date
 /// # Note
-/// * The function will return `DateError` if the system date is invalid
-pub fn get_today_bengali_date() -> Result<Date, DateError> {
-    let today = Local::now();
-
-    let today_day: u8 = match today.day().try_into() {
+/// * The function will return `DateError::OutOfRange` if the year of the date is out of the supported range
+fn local_day_to_bengali_date<Tz: TimeZone>(
+    moment: &DateTime<Tz>,
+    variant: CalendarVariant,
+) -> Result<Date, DateError> {
+    let today_day: u8 = match moment.day().try_into() {
         Ok(day) => day,
         Err(err) => {
             return Err(DateError::CastingError(err)); // or handle the error as needed
         }
     };
 
-    let today_month: u8 = match today.month().try_into() {
+    let today_month: u8 = match moment.month().try_into() {
         Ok(month) => month,
         Err(err) => {
             return Err(DateError::CastingError(err)); // or handle the error as needed
         }
     };
 
-    let today_year: u16 = match today.year().try_into() {
+    let today_year: u16 = match moment.year().try_into() {
         Ok(year) => year,
-        Err(err) => {
-            return Err(DateError::CastingError(err)); // or handle the error as needed
+        Err(_) => {
+            return Err(DateError::out_of_range(
+                DateField::Year,
+                moment.year() as i64,
+                593,
+                9999,
+            ))
         }
     };
 
@@ -304,19 +417,91 @@ pub fn get_today_bengali_date() -> Result<Date, DateError> {
             let english_date = EnglishDate::create_date(today_day, month, today_year);
 
             match english_date {
-                Ok(date) => match gregorian_to_bengali_date(date) {
-                    Ok(bengali_date) => Ok(bengali_date),
-                    Err(err) => Err(err),
-                },
-                Err(err) => return Err(err),
+                Ok(date) => gregorian_to_bengali_date(date, variant),
+                Err(err) => Err(err),
             }
         }
-        Err(err) => match err {
-            _ => return Err(DateError::WrongMonth(err)),
-        },
+        Err(err) => Err(DateError::WrongMonth(err)),
     }
 }
 
+/// Get today's Bengali date
+/// # Returns
+/// * `Result<Date, DateError>` - Bengali date
+/// # Example
+/// ```
+/// use ponjika::calendar;
+/// let today = calendar::get_today_bengali_date();
+/// match today {
+///   Ok(bengali_date) => {
+///     println!("{}", bengali_date.to_string());
+///   }
+///   Err(_) => {
+///     eprintln!("The date is not a valid greogrian date");
+///   }
+/// }
+/// ```
+/// # Note
+/// * The function will use the time zone of the system, use `get_today_bengali_date_in` for the date in Bangladesh or India
+/// * The function will return `DateError::OutOfRange` if the year of the system date is out of the supported range
+pub fn get_today_bengali_date() -> Result<Date, DateError> {
+    local_day_to_bengali_date(&Local::now(), CalendarVariant::default())
+}
+
+/// Get today's Bengali date in a time zone
+/// # Arguments
+/// * `time_zone` - BengaliTimeZone
+/// # Returns
+/// * `Result<Date, DateError>` - Bengali date
+/// # Example
+/// ```
+/// use ponjika::calendar;
+/// use ponjika::clock::BengaliTimeZone;
+/// let today = calendar::get_today_bengali_date_in(BengaliTimeZone::Dhaka);
+/// assert!(today.is_ok());
+/// ```
+/// # Note
+/// * The function will use the `CalendarVariant::Revised2019` rules
+/// * The function will return `DateError::OutOfRange` if the year of the system date is out of the supported range
+pub fn get_today_bengali_date_in(time_zone: BengaliTimeZone) -> Result<Date, DateError> {
+    get_today_bengali_date_with_clock(
+        &SystemClock,
+        &time_zone.get_offset(),
+        CalendarVariant::default(),
+    )
+}
+
+/// Get today's Bengali date from a clock in a time zone
+/// # Arguments
+/// * `clock` - Clock
+/// * `time_zone` - chrono::TimeZone
+/// * `variant` - CalendarVariant
+/// # Returns
+/// * `Result<Date, DateError>` - Bengali date
+/// # Example
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use ponjika::calendar::{self, CalendarVariant};
+/// use ponjika::clock::{BengaliTimeZone, FixedClock};
+/// // 00:30 on 14th April in Dhaka, but still 13th April in UTC
+/// let clock = FixedClock::new(Utc.with_ymd_and_hms(2024, 4, 13, 18, 30, 0).unwrap());
+/// let dhaka = BengaliTimeZone::Dhaka.get_offset();
+/// let today = calendar::get_today_bengali_date_with_clock(&clock, &dhaka, CalendarVariant::Revised2019);
+/// assert_eq!(today.unwrap().get_bengali_date().unwrap().get_date(), (1, 1, 1431));
+/// let today = calendar::get_today_bengali_date_with_clock(&clock, &Utc, CalendarVariant::Revised2019);
+/// assert_eq!(today.unwrap().get_bengali_date().unwrap().get_date(), (30, 12, 1430));
+/// ```
+/// # Note
+/// * The day starts at midnight in the time zone
+/// * The function will return `DateError::OutOfRange` if the year of the clock is out of the supported range
+pub fn get_today_bengali_date_with_clock<C: Clock + ?Sized, Tz: TimeZone>(
+    clock: &C,
+    time_zone: &Tz,
+    variant: CalendarVariant,
+) -> Result<Date, DateError> {
+    local_day_to_bengali_date(&clock.now().with_timezone(time_zone), variant)
+}
+
 /// Get Bengali date from Gregorian date
 /// # Arguments
 /// * `english_date` - EnglishDate
@@ -344,14 +529,106 @@ pub fn get_today_bengali_date() -> Result<Date, DateError> {
 /// }
 /// ```
 /// # Note
-/// * The function will return `DateError` if the conversion fails
+/// * The function will use the `CalendarVariant::Revised2019` rules
+/// * The function will return `DateError::OutOfRange` if the year of the converted date is out of the supported range
 pub fn get_bengali_date_from_gregorian(english_date: EnglishDate) -> Result<Date, DateError> {
-    match gregorian_to_bengali_date(english_date) {
-        Ok(date) => Ok(date),
-        Err(err) => Err(err),
+    gregorian_to_bengali_date(english_date, CalendarVariant::default())
+}
+
+/// Get Bengali date from a moment at a location
+/// # Arguments
+/// * `moment` - DateTime<Tz>
+/// * `latitude` - f64, degrees north
+/// * `longitude` - f64, degrees east
+/// * `boundary` - DayBoundary
+/// * `variant` - CalendarVariant
+/// # Returns
+/// * `Result<Date, DateError>` - Bengali date
+/// # Example
+/// ```
+/// use chrono::DateTime;
+/// use ponjika::calendar::{self, CalendarVariant, DayBoundary};
+/// // 03:00 on 14th April 2024 in Kolkata, before the sunrise of the first day of 1431
+/// let moment = DateTime::parse_from_rfc3339("2024-04-14T03:00:00+05:30").unwrap();
+/// let bengali_date = calendar::get_bengali_date_from_datetime(
+///     moment, 22.57, 88.36, DayBoundary::Sunrise, CalendarVariant::Traditional);
+/// assert_eq!(bengali_date.unwrap().get_bengali_date().unwrap().get_date(), (30, 12, 1430));
+/// let bengali_date = calendar::get_bengali_date_from_datetime(
+///     moment, 22.57, 88.36, DayBoundary::Midnight, CalendarVariant::Traditional);
+/// assert_eq!(bengali_date.unwrap().get_bengali_date().unwrap().get_date(), (1, 1, 1431));
+/// ```
+/// # Note
+/// * `DayBoundary::Midnight` uses the date of the moment in its own time zone and ignores the location
+/// * `DayBoundary::Sunrise` uses the local sunrise, so the time zone of the moment does not matter
+/// * The function will return `DateError::WrongLocation` if the latitude or the longitude is out of range
+/// * The function will return `DateError::NoSunrise` if the sun does not rise or set on the day at the location
+/// * The function will return `DateError::OutOfRange` if the year of the converted date is out of the supported range
+pub fn get_bengali_date_from_datetime<Tz: TimeZone>(
+    moment: DateTime<Tz>,
+    latitude: f64,
+    longitude: f64,
+    boundary: DayBoundary,
+    variant: CalendarVariant,
+) -> Result<Date, DateError> {
+    if !(-90.0..=90.0).contains(&latitude) || !(-180.0..=180.0).contains(&longitude) {
+        return Err(DateError::WrongLocation {
+            latitude,
+            longitude,
+        });
+    }
+
+    match boundary {
+        DayBoundary::Midnight => local_day_to_bengali_date(&moment, variant),
+        DayBoundary::Sunrise => {
+            let utc = moment.naive_utc();
+            let instant =
+                utc.num_days_from_ce() as f64 + utc.num_seconds_from_midnight() as f64 / 86_400.0;
+
+            // the day at the location by the local mean time
+            let mut day = (instant + longitude / 360.0).floor() as i64;
+            match sun::sunrise(day, latitude, longitude) {
+                Some(sunrise) if instant < sunrise => day -= 1,
+                Some(_) => {}
+                None => return Err(DateError::NoSunrise),
+            }
+
+            match BengaliDate::from_rata_die(RataDie::new(day), variant) {
+                Ok(bengali_date) => Ok(Date::Bengali(bengali_date)),
+                Err(err) => Err(err),
+            }
+        }
     }
 }
 
+/// Get Bengali date from Gregorian date using the rules of a calendar variant
+/// # Arguments
+/// * `english_date` - EnglishDate
+/// * `variant` - CalendarVariant
+/// # Returns
+/// * `Result<Date, DateError>` - Bengali date
+/// # Example
+/// ```
+/// use ponjika::{calendar, CalendarVariant, EnglishDate, EnglishMonths};
+/// let english_date = EnglishDate::create_date(16, EnglishMonths::October, 2010).unwrap();
+/// let bengali_date =
+///     calendar::get_bengali_date_from_gregorian_with_variant(english_date, CalendarVariant::Revised1987);
+/// assert_eq!(bengali_date.unwrap().get_date().unwrap().2, "কার্তিক");
+///
+/// let english_date = EnglishDate::create_date(16, EnglishMonths::October, 2010).unwrap();
+/// let bengali_date =
+///     calendar::get_bengali_date_from_gregorian_with_variant(english_date, CalendarVariant::Revised2019);
+/// assert_eq!(bengali_date.unwrap().get_date().unwrap().2, "আশ্বিন");
+/// ```
+/// # Note
+/// * The Bengali date will keep the variant, so converting it back uses the same rules
+/// * The function will return `DateError::OutOfRange` if the year of the converted date is out of the supported range
+pub fn get_bengali_date_from_gregorian_with_variant(
+    english_date: EnglishDate,
+    variant: CalendarVariant,
+) -> Result<Date, DateError> {
+    gregorian_to_bengali_date(english_date, variant)
+}
+
 /// Get Gregorian date from Bengali date
 /// # Arguments
 /// * `bengali_date` - BengaliDate
@@ -379,10 +656,103 @@ pub fn get_bengali_date_from_gregorian(english_date: EnglishDate) -> Result<Date
 /// }
 /// ```
 /// # Note
-/// * The function will return `DateError` if the conversion fails
+/// * The function will use the calendar variant of the Bengali date
+/// * The function will return `DateError::OutOfRange` if the year of the converted date is out of the supported range
 pub fn get_gregorian_date_from_bengali(bengali_date: BengaliDate) -> Result<Date, DateError> {
-    match bengali_to_gregorian_date(bengali_date) {
-        Ok(date) => Ok(date),
+    bengali_to_gregorian_date(bengali_date)
+}
+
+/// Get Bengali date from Julian date
+/// # Arguments
+/// * `julian_date` - JulianDate
+/// # Returns
+/// * `Result<Date, DateError>` - Bengali date
+/// # Example
+/// ```
+/// use ponjika::{calendar, EnglishMonths, JulianDate};
+/// let julian_date = JulianDate::create_date(4, EnglishMonths::April, 1600).unwrap();
+/// let bengali_date = calendar::get_bengali_date_from_julian(julian_date).unwrap();
+/// assert_eq!(bengali_date.get_bengali_date().unwrap().get_date(), (1, 1, 1007));
+/// ```
+/// # Note
+/// * The function will use the `CalendarVariant::Revised2019` rules
+/// * The function will return `DateError::OutOfRange` if the year of the converted date is out of the supported range
+pub fn get_bengali_date_from_julian(julian_date: JulianDate) -> Result<Date, DateError> {
+    get_bengali_date_from_julian_with_variant(julian_date, CalendarVariant::default())
+}
+
+/// Get Bengali date from Julian date using the rules of a calendar variant
+/// # Arguments
+/// * `julian_date` - JulianDate
+/// * `variant` - CalendarVariant
+/// # Returns
+/// * `Result<Date, DateError>` - Bengali date
+/// # Example
+/// ```
+/// use ponjika::{calendar, CalendarVariant, EnglishMonths, JulianDate};
+/// let julian_date = JulianDate::create_date(4, EnglishMonths::April, 1600).unwrap();
+/// let bengali_date =
+///     calendar::get_bengali_date_from_julian_with_variant(julian_date, CalendarVariant::Historical);
+/// assert!(bengali_date.is_ok());
+/// ```
+/// # Note
+/// * The function will return `DateError::OutOfRange` if the year of the converted date is out of the supported range
+pub fn get_bengali_date_from_julian_with_variant(
+    julian_date: JulianDate,
+    variant: CalendarVariant,
+) -> Result<Date, DateError> {
+    match BengaliDate::from_rata_die(julian_date.to_rata_die(), variant) {
+        Ok(bengali_date) => Ok(Date::Bengali(bengali_date)),
+        Err(err) => Err(err),
+    }
+}
+
+/// Get Julian date from Bengali date
+/// # Arguments
+/// * `bengali_date` - BengaliDate
+/// # Returns
+/// * `Result<Date, DateError>` - Julian date
+/// # Example
+/// ```
+/// use ponjika::{calendar, BengaliDate, BengaliMonths};
+/// let bengali_date = BengaliDate::create_date(1, BengaliMonths::Baishakh, 1007).unwrap();
+/// let julian_date = calendar::get_julian_date_from_bengali(bengali_date).unwrap();
+/// assert_eq!(julian_date.to_string(), "Friday, 4 April 1600");
+/// ```
+/// # Note
+/// * The function will use the calendar variant of the Bengali date
+/// * The function will return the Julian date even after the Gregorian reform
+/// * The function will return `DateError::OutOfRange` if the year of the converted date is out of the supported range
+pub fn get_julian_date_from_bengali(bengali_date: BengaliDate) -> Result<Date, DateError> {
+    match JulianDate::from_rata_die(bengali_date.to_rata_die()) {
+        Ok(julian_date) => Ok(Date::Julian(julian_date)),
         Err(err) => Err(err),
     }
 }
+
+/// Get the English date from Bengali date in the calendar in use on the day
+/// # Arguments
+/// * `bengali_date` - BengaliDate
+/// * `switchover` - Switchover
+/// # Returns
+/// * `Result<Date, DateError>` - Julian date before the switchover, Gregorian date from it
+/// # Example
+/// ```
+/// use ponjika::{calendar, BengaliDate, BengaliMonths, Switchover};
+/// let bengali_date = BengaliDate::create_date(1, BengaliMonths::Baishakh, 980).unwrap();
+/// let date = calendar::get_english_date_from_bengali_with_switchover(bengali_date, Switchover::default());
+/// assert_eq!(date.unwrap().to_string(), "Saturday, 4 April 1573");
+///
+/// let bengali_date = BengaliDate::create_date(1, BengaliMonths::Baishakh, 1431).unwrap();
+/// let date = calendar::get_english_date_from_bengali_with_switchover(bengali_date, Switchover::default());
+/// assert_eq!(date.unwrap().to_string(), "Sunday, 14 April 2024");
+/// ```
+/// # Note
+/// * The function will use the calendar variant of the Bengali date
+/// * The function will return `DateError::OutOfRange` if the year of the converted date is out of the supported range
+pub fn get_english_date_from_bengali_with_switchover(
+    bengali_date: BengaliDate,
+    switchover: Switchover,
+) -> Result<Date, DateError> {
+    switchover.from_rata_die(bengali_date.to_rata_die())
+}
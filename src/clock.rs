@@ -0,0 +1,97 @@
+//! # Clock: The source of the current time
+//! The `clock` module provides the `Clock` trait used to find out what "today" is.
+//! The `SystemClock` struct reads the system time and the `FixedClock` struct always returns the same moment.
+//! The `BengaliTimeZone` enum is used to select the time zone the Bengali date is counted in.
+
+use chrono::{DateTime, FixedOffset, Utc};
+
+/// # `Clock`: The trait for the sources of the current time.
+/// Implement the trait to control the moment that is considered "now".
+pub trait Clock {
+    /// Get the current moment
+    /// # Returns
+    /// * `DateTime<Utc>` - The current moment in UTC
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// # `SystemClock`: The clock of the operating system.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    /// Get the current moment from the operating system
+    /// # Returns
+    /// * `DateTime<Utc>` - The current moment in UTC
+    /// # Example
+    /// ```
+    /// use ponjika::clock::{Clock, SystemClock};
+    /// println!("{}", SystemClock.now());
+    /// ```
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// # `FixedClock`: A clock that always returns the same moment.
+/// The struct is used to pin "now" in tests.
+#[derive(Debug, Clone, Copy)]
+pub struct FixedClock {
+    moment: DateTime<Utc>,
+}
+
+impl FixedClock {
+    /// Create a fixed clock
+    /// # Arguments
+    /// * `moment` - DateTime<Utc>
+    /// # Returns
+    /// * `FixedClock` - The clock stopped at the moment
+    /// # Example
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use ponjika::clock::{Clock, FixedClock};
+    /// let moment = Utc.with_ymd_and_hms(2024, 4, 13, 18, 30, 0).unwrap();
+    /// let clock = FixedClock::new(moment);
+    /// assert_eq!(clock.now(), moment);
+    /// ```
+    pub fn new(moment: DateTime<Utc>) -> Self {
+        FixedClock { moment }
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.moment
+    }
+}
+
+/// # `BengaliTimeZone`: The time zones where the Bengali calendar is used.
+/// The enum variants are the time zones of Bangladesh and India.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BengaliTimeZone {
+    /// Bangladesh Standard Time (Asia/Dhaka), six hours ahead of UTC.
+    #[default]
+    Dhaka,
+    /// Indian Standard Time (Asia/Kolkata), five and a half hours ahead of UTC.
+    Kolkata,
+}
+
+impl BengaliTimeZone {
+    /// Get the offset of the time zone from UTC
+    /// # Returns
+    /// * `FixedOffset` - The offset of the time zone
+    /// # Example
+    /// ```
+    /// use ponjika::clock::BengaliTimeZone;
+    /// assert_eq!(BengaliTimeZone::Dhaka.get_offset().local_minus_utc(), 6 * 3600);
+    /// assert_eq!(BengaliTimeZone::Kolkata.get_offset().local_minus_utc(), 5 * 3600 + 1800);
+    /// ```
+    /// # Note
+    /// * Neither time zone observes daylight saving time, so the offset is fixed
+    pub fn get_offset(&self) -> FixedOffset {
+        let seconds = match self {
+            BengaliTimeZone::Dhaka => 6 * 3600,
+            BengaliTimeZone::Kolkata => 5 * 3600 + 1800,
+        };
+        FixedOffset::east_opt(seconds).unwrap()
+    }
+}
@@ -0,0 +1,450 @@
+//! # Convert: Converting dates between any two calendars
+//! The `convert` module provides the `Calendar` trait, the common interface of the calendars.
+//! The `Gregorian`, `Julian` and `Bengali` structs are the calendars of the crate.
+//! The `DateIn` struct is a date of any calendar, and `to` converts it to another calendar through the fixed day number.
+//! A new calendar, such as the Hijri or the Saka calendar, only needs to implement the `Calendar` trait.
+
+use std::ops::RangeInclusive;
+
+use crate::calendar::{self, CalendarVariant};
+use crate::date::{BengaliDate, DateError, DateField, EnglishDate, JulianDate};
+use crate::months::{BengaliMonths, EnglishMonths, MonthError};
+use crate::rata_die::{self, RataDie};
+
+/// # `Calendar`: The trait for the calendars.
+/// Implement the trait to convert the dates of a calendar to and from the other calendars.
+/// The months and the days are counted from 1.
+pub trait Calendar: Copy + Default {
+    /// Get the name of the calendar
+    fn get_name(&self) -> &'static str;
+
+    /// Get the years the calendar supports
+    fn get_year_range(&self) -> RangeInclusive<u16>;
+
+    /// Get the number of months of a year
+    fn get_months_in_year(&self, year: u16) -> u8;
+
+    /// Get the number of days of a month, 0 if the month does not exist
+    fn get_days_in_month(&self, month: u8, year: u16) -> u8;
+
+    /// Get the name of a month
+    fn get_month_name(&self, month: u8) -> Result<String, MonthError>;
+
+    /// Get the fixed day number of a valid date
+    fn to_rata_die(&self, day: u8, month: u8, year: u16) -> RataDie;
+
+    /// Get the day, month and year of a fixed day number
+    fn date_from_rata_die(&self, rata_die: RataDie) -> Result<(u8, u8, u16), DateError>;
+
+    /// Check whether a date exists in the calendar
+    /// # Arguments
+    /// * `day` - u8
+    /// * `month` - u8
+    /// * `year` - u16
+    /// # Returns
+    /// * `Result<(), DateError>` - Nothing if the date exists
+    /// # Note
+    /// * The function will return `DateError::OutOfRange` with the field and the allowed range if the year, month or day does not exist
+    fn validate(&self, day: u8, month: u8, year: u16) -> Result<(), DateError> {
+        let years = self.get_year_range();
+        if !years.contains(&year) {
+            return Err(DateError::out_of_range(
+                DateField::Year,
+                year,
+                *years.start(),
+                *years.end(),
+            ));
+        }
+
+        let months = self.get_months_in_year(year);
+        if !(1..=months).contains(&month) {
+            return Err(DateError::out_of_range(DateField::Month, month, 1, months));
+        }
+
+        let days = self.get_days_in_month(month, year);
+        if !(1..=days).contains(&day) {
+            return Err(DateError::out_of_range(DateField::Day, day, 1, days));
+        }
+
+        Ok(())
+    }
+}
+
+/// # `Gregorian`: The Gregorian calendar.
+/// The calendar of the `EnglishDate` struct.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Gregorian;
+
+impl Calendar for Gregorian {
+    fn get_name(&self) -> &'static str {
+        "Gregorian"
+    }
+
+    fn get_year_range(&self) -> RangeInclusive<u16> {
+        593..=9999
+    }
+
+    fn get_months_in_year(&self, _year: u16) -> u8 {
+        12
+    }
+
+    fn get_days_in_month(&self, month: u8, year: u16) -> u8 {
+        match EnglishMonths::get_month(month) {
+            Ok(month) => month.days_in(year),
+            Err(_) => 0,
+        }
+    }
+
+    fn get_month_name(&self, month: u8) -> Result<String, MonthError> {
+        EnglishMonths::get_month(month).map(|month| month.to_string())
+    }
+
+    fn to_rata_die(&self, day: u8, month: u8, year: u16) -> RataDie {
+        RataDie::new(rata_die::fixed_from_gregorian(day, month, year as i64))
+    }
+
+    fn date_from_rata_die(&self, rata_die: RataDie) -> Result<(u8, u8, u16), DateError> {
+        EnglishDate::from_rata_die(rata_die).map(|date| date.get_date())
+    }
+}
+
+/// # `Julian`: The Julian calendar.
+/// The calendar of the `JulianDate` struct.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Julian;
+
+impl Calendar for Julian {
+    fn get_name(&self) -> &'static str {
+        "Julian"
+    }
+
+    fn get_year_range(&self) -> RangeInclusive<u16> {
+        593..=9999
+    }
+
+    fn get_months_in_year(&self, _year: u16) -> u8 {
+        12
+    }
+
+    fn get_days_in_month(&self, month: u8, year: u16) -> u8 {
+        match month {
+            1..=12 => rata_die::julian_days_in_month(month, year as i64),
+            _ => 0,
+        }
+    }
+
+    fn get_month_name(&self, month: u8) -> Result<String, MonthError> {
+        EnglishMonths::get_month(month).map(|month| month.to_string())
+    }
+
+    fn to_rata_die(&self, day: u8, month: u8, year: u16) -> RataDie {
+        RataDie::new(rata_die::fixed_from_julian(day, month, year as i64))
+    }
+
+    fn date_from_rata_die(&self, rata_die: RataDie) -> Result<(u8, u8, u16), DateError> {
+        JulianDate::from_rata_die(rata_die).map(|date| date.get_date())
+    }
+}
+
+/// # `Bengali`: The Bengali calendar.
+/// The calendar of the `BengaliDate` struct, following one of the calendar variants.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Bengali {
+    variant: CalendarVariant,
+}
+
+impl Bengali {
+    /// Create the Bengali calendar of a calendar variant
+    /// # Arguments
+    /// * `variant` - CalendarVariant
+    /// # Returns
+    /// * `Bengali` - The calendar
+    /// # Example
+    /// ```
+    /// use ponjika::calendar::CalendarVariant;
+    /// use ponjika::convert::Bengali;
+    /// let calendar = Bengali::new(CalendarVariant::Traditional);
+    /// assert_eq!(calendar.get_variant(), CalendarVariant::Traditional);
+    /// ```
+    pub fn new(variant: CalendarVariant) -> Self {
+        Bengali { variant }
+    }
+
+    /// Get the calendar variant of the selected calendar
+    /// # Returns
+    /// * `CalendarVariant` - The rule set the calendar follows
+    pub fn get_variant(&self) -> CalendarVariant {
+        self.variant
+    }
+}
+
+impl Calendar for Bengali {
+    fn get_name(&self) -> &'static str {
+        "Bengali"
+    }
+
+    fn get_year_range(&self) -> RangeInclusive<u16> {
+        1..=9406
+    }
+
+    fn get_months_in_year(&self, _year: u16) -> u8 {
+        12
+    }
+
+    fn get_days_in_month(&self, month: u8, year: u16) -> u8 {
+        match BengaliMonths::get_month(month) {
+            Ok(month) => month.days_in_with_variant(year, self.variant),
+            Err(_) => 0,
+        }
+    }
+
+    fn get_month_name(&self, month: u8) -> Result<String, MonthError> {
+        BengaliMonths::get_month(month).map(|month| month.to_string())
+    }
+
+    fn to_rata_die(&self, day: u8, month: u8, year: u16) -> RataDie {
+        RataDie::new(calendar::fixed_from_bengali(
+            day,
+            month,
+            year as i64,
+            self.variant,
+        ))
+    }
+
+    fn date_from_rata_die(&self, rata_die: RataDie) -> Result<(u8, u8, u16), DateError> {
+        BengaliDate::from_rata_die(rata_die, self.variant).map(|date| date.get_date())
+    }
+}
+
+/// # `DateIn`: A date of any calendar.
+/// The struct holds a valid day, month and year of the calendar `C`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DateIn<C: Calendar> {
+    day: u8,
+    month: u8,
+    year: u16,
+    calendar: C,
+}
+
+impl<C: Calendar> DateIn<C> {
+    /// Create a date of the default calendar of the type
+    /// # Arguments
+    /// * `day` - u8
+    /// * `month` - u8
+    /// * `year` - u16
+    /// # Returns
+    /// * `Result<DateIn<C>, DateError>` - The date
+    /// # Example
+    /// ```
+    /// use ponjika::convert::{DateIn, Gregorian};
+    /// let date = DateIn::<Gregorian>::new(14, 4, 2024).unwrap();
+    /// assert_eq!(date.get_date(), (14, 4, 2024));
+    /// assert!(DateIn::<Gregorian>::new(29, 2, 2023).is_err());
+    /// ```
+    /// # Note
+    /// * The function will return `DateError::OutOfRange` with the field and the allowed range if the date does not exist in the calendar
+    pub fn new(day: u8, month: u8, year: u16) -> Result<Self, DateError> {
+        Self::new_in(day, month, year, C::default())
+    }
+
+    /// Create a date of a calendar
+    /// # Arguments
+    /// * `day` - u8
+    /// * `month` - u8
+    /// * `year` - u16
+    /// * `calendar` - C
+    /// # Returns
+    /// * `Result<DateIn<C>, DateError>` - The date
+    /// # Example
+    /// ```
+    /// use ponjika::calendar::CalendarVariant;
+    /// use ponjika::convert::{Bengali, DateIn};
+    /// let calendar = Bengali::new(CalendarVariant::Traditional);
+    /// assert!(DateIn::new_in(32, 4, 1431, calendar).is_ok());
+    /// assert!(DateIn::<Bengali>::new(32, 4, 1431).is_err());
+    /// ```
+    /// # Note
+    /// * The function will return `DateError::OutOfRange` with the field and the allowed range if the date does not exist in the calendar
+    pub fn new_in(day: u8, month: u8, year: u16, calendar: C) -> Result<Self, DateError> {
+        calendar.validate(day, month, year)?;
+
+        Ok(DateIn {
+            day,
+            month,
+            year,
+            calendar,
+        })
+    }
+
+    /// Create a date of a calendar from a fixed day number
+    /// # Arguments
+    /// * `rata_die` - RataDie
+    /// * `calendar` - C
+    /// # Returns
+    /// * `Result<DateIn<C>, DateError>` - The date
+    /// # Note
+    /// * The function will return `DateError::OutOfRange` if the year of the date is out of the supported range
+    pub fn from_rata_die(rata_die: RataDie, calendar: C) -> Result<Self, DateError> {
+        let (day, month, year) = calendar.date_from_rata_die(rata_die)?;
+        Self::new_in(day, month, year, calendar)
+    }
+
+    /// Get the day, month and year of the selected date
+    /// # Returns
+    /// * `(u8, u8, u16)` - The day, month and year
+    pub fn get_date(&self) -> (u8, u8, u16) {
+        (self.day, self.month, self.year)
+    }
+
+    /// Get the calendar of the selected date
+    /// # Returns
+    /// * `C` - The calendar
+    pub fn get_calendar(&self) -> C {
+        self.calendar
+    }
+
+    /// Get the month name of the selected date
+    /// # Returns
+    /// * `Result<String, MonthError>` - The name of the month in the calendar
+    /// # Example
+    /// ```
+    /// use ponjika::convert::{Bengali, DateIn};
+    /// let date = DateIn::<Bengali>::new(1, 1, 1431).unwrap();
+    /// assert_eq!(date.get_month_name().unwrap(), "বৈশাখ");
+    /// ```
+    pub fn get_month_name(&self) -> Result<String, MonthError> {
+        self.calendar.get_month_name(self.month)
+    }
+
+    /// Get the number of days of the month of the selected date
+    /// # Returns
+    /// * `u8` - The number of days
+    pub fn get_days_in_month(&self) -> u8 {
+        self.calendar.get_days_in_month(self.month, self.year)
+    }
+
+    /// Get the fixed day number of the selected date
+    /// # Returns
+    /// * `RataDie` - The fixed day number
+    pub fn to_rata_die(&self) -> RataDie {
+        self.calendar.to_rata_die(self.day, self.month, self.year)
+    }
+
+    /// Convert the selected date to the default calendar of another type
+    /// # Returns
+    /// * `Result<DateIn<T>, DateError>` - The same day in the other calendar
+    /// # Example
+    /// ```
+    /// use ponjika::convert::{Bengali, DateIn, Gregorian, Julian};
+    /// let date = DateIn::<Gregorian>::new(14, 4, 2024).unwrap();
+    /// assert_eq!(date.to::<Bengali>().unwrap().get_date(), (1, 1, 1431));
+    /// assert_eq!(date.to::<Julian>().unwrap().get_date(), (1, 4, 2024));
+    /// ```
+    /// # Note
+    /// * The function will return `DateError::OutOfRange` if the day is out of the supported range of the other calendar
+    pub fn to<T: Calendar>(&self) -> Result<DateIn<T>, DateError> {
+        self.to_calendar(T::default())
+    }
+
+    /// Convert the selected date to another calendar
+    /// # Arguments
+    /// * `calendar` - T
+    /// # Returns
+    /// * `Result<DateIn<T>, DateError>` - The same day in the other calendar
+    /// # Example
+    /// ```
+    /// use ponjika::calendar::CalendarVariant;
+    /// use ponjika::convert::{Bengali, DateIn, Gregorian};
+    /// let date = DateIn::<Gregorian>::new(14, 4, 2024).unwrap();
+    /// let calendar = Bengali::new(CalendarVariant::Revised1987);
+    /// assert_eq!(date.to_calendar(calendar).unwrap().get_date(), (1, 1, 1431));
+    /// ```
+    /// # Note
+    /// * The function will return `DateError::OutOfRange` if the day is out of the supported range of the other calendar
+    pub fn to_calendar<T: Calendar>(&self, calendar: T) -> Result<DateIn<T>, DateError> {
+        DateIn::from_rata_die(self.to_rata_die(), calendar)
+    }
+}
+
+impl From<EnglishDate> for DateIn<Gregorian> {
+    fn from(date: EnglishDate) -> Self {
+        let (day, month, year) = date.get_date();
+        DateIn {
+            day,
+            month,
+            year,
+            calendar: Gregorian,
+        }
+    }
+}
+
+impl From<JulianDate> for DateIn<Julian> {
+    fn from(date: JulianDate) -> Self {
+        let (day, month, year) = date.get_date();
+        DateIn {
+            day,
+            month,
+            year,
+            calendar: Julian,
+        }
+    }
+}
+
+impl From<BengaliDate> for DateIn<Bengali> {
+    fn from(date: BengaliDate) -> Self {
+        let (day, month, year) = date.get_date();
+        DateIn {
+            day,
+            month,
+            year,
+            calendar: Bengali::new(date.get_variant()),
+        }
+    }
+}
+
+impl TryFrom<DateIn<Gregorian>> for EnglishDate {
+    type Error = DateError;
+
+    fn try_from(date: DateIn<Gregorian>) -> Result<Self, Self::Error> {
+        match EnglishMonths::get_month(date.month) {
+            Ok(month) => EnglishDate::create_date(date.day, month, date.year),
+            Err(err) => Err(DateError::WrongMonth(err)),
+        }
+    }
+}
+
+impl TryFrom<DateIn<Julian>> for JulianDate {
+    type Error = DateError;
+
+    fn try_from(date: DateIn<Julian>) -> Result<Self, Self::Error> {
+        match EnglishMonths::get_month(date.month) {
+            Ok(month) => JulianDate::create_date(date.day, month, date.year),
+            Err(err) => Err(DateError::WrongMonth(err)),
+        }
+    }
+}
+
+impl TryFrom<DateIn<Bengali>> for BengaliDate {
+    type Error = DateError;
+
+    /// Convert the date back to a `BengaliDate`
+    /// # Example
+    /// ```
+    /// use ponjika::convert::{Bengali, DateIn, Gregorian};
+    /// use ponjika::date::BengaliDate;
+    /// let date = DateIn::<Gregorian>::new(21, 2, 2024).unwrap().to::<Bengali>().unwrap();
+    /// let date = BengaliDate::try_from(date).unwrap();
+    /// assert_eq!(date.get_week_day().unwrap(), "বুধবার");
+    /// ```
+    fn try_from(date: DateIn<Bengali>) -> Result<Self, Self::Error> {
+        match BengaliMonths::get_month(date.month) {
+            Ok(month) => BengaliDate::create_date_with_variant(
+                date.day,
+                month,
+                date.year,
+                date.calendar.variant,
+            ),
+            Err(err) => Err(DateError::WrongMonth(err)),
+        }
+    }
+}
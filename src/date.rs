@@ -2,37 +2,144 @@
 //! The `date` module is used to represent both English and Bengali dates.
 //! The `Date` enum is used to represent both English and Bengali dates.
 //! The `EnglishDate` and `BengaliDate` struct variants are the English and Bengali dates respectively.
+//! The `JulianDate` struct is used for the English dates written before the Gregorian reform.
 //! The `DateError` enum is used to represent the error when the date is invalid.
 
 use std::fmt::{self};
 use std::num::TryFromIntError;
 
-use chrono::{Datelike, TimeZone, Utc, Weekday};
-
+use crate::calendar::{self, CalendarVariant};
 use crate::days::{BengaliWeekDays, EnglishWeekDays, WeekDayError, WeekDays};
+use crate::dual::DualDate;
 use crate::months::{BengaliMonths, EnglishMonths, Month};
+use crate::numerals::BengaliNumeral;
+use crate::rata_die::{self, RataDie};
 use crate::MonthError;
 
+/// # `DateField`: The parts of a date.
+/// The enum variant is the part of the date that an error is about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DateField {
+    /// The day of the month
+    Day,
+    /// The month of the year
+    Month,
+    /// The year
+    Year,
+    /// The day of the year
+    Ordinal,
+    /// The fixed day number of the `rata_die` module
+    DayNumber,
+}
+
+impl fmt::Display for DateField {
+    /// Display the name of the part of the date
+    /// # Returns
+    /// * `fmt::Result` - The name in lower case
+    /// # Example
+    /// ```
+    /// use ponjika::date::DateField;
+    /// assert_eq!(DateField::Ordinal.to_string(), "day of the year");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let field = match self {
+            DateField::Day => "day",
+            DateField::Month => "month",
+            DateField::Year => "year",
+            DateField::Ordinal => "day of the year",
+            DateField::DayNumber => "day number",
+        };
+        write!(f, "{}", field)
+    }
+}
+
 /// # `DateError`: The error enum for the dates.
 /// The enum variant is the error message.
 #[derive(Debug)]
 pub enum DateError {
-    /// The UnknownDate variant is used when the date is invalid.
+    /// The UnknownDate variant is used when the date is `Date::Unknown`.
     UnknownDate,
     /// The WrongWeekDay variant is used when the week day is invalid.
     WrongWeekDay(WeekDayError),
     /// The WrongMonth variant is used when the month is invalid.
     WrongMonth(MonthError),
-    /// The WrongDay variant is used when the day is invalid.
-    WrongDay,
-    /// The WrongYear variant is used when the year is invalid.
-    WrongYear,
+    /// The OutOfRange variant is used when a part of the date is outside of the values it can take.
+    OutOfRange {
+        /// The part of the date
+        field: DateField,
+        /// The value that was given
+        value: i64,
+        /// The smallest value allowed
+        min: i64,
+        /// The largest value allowed
+        max: i64,
+    },
     /// The NumToCharError variant is used when the number to character conversion failed.
     NumToCharError,
     /// The CastingError variant is used when the casting failed.
     CastingError(TryFromIntError),
-    /// The ArithmeticError variant is used when the arithmetic operation failed.
+    /// The ArithmeticError variant is used when the number of days overflowed.
     ArithmeticError,
+    /// The DayOutOfMonth variant is used when the month of the year has fewer days than the day.
+    DayOutOfMonth {
+        /// The day that was given
+        day: u8,
+        /// The month of the date
+        month: Month,
+        /// The year of the date
+        year: u16,
+        /// The number of days in the month of the year
+        days: u8,
+    },
+    /// The WeekDayMismatch variant is used when the given week day is not the week day of the date.
+    WeekDayMismatch {
+        /// The week day that was given
+        given: BengaliWeekDays,
+        /// The week day the date falls on
+        actual: BengaliWeekDays,
+    },
+    /// The SkippedDay variant is used when the day was skipped by the switchover to the Gregorian calendar.
+    SkippedDay {
+        /// The day that was given
+        day: u8,
+        /// The month that was given
+        month: EnglishMonths,
+        /// The year that was given
+        year: u16,
+    },
+    /// The WrongLocation variant is used when the latitude or the longitude is out of range.
+    WrongLocation {
+        /// The latitude that was given, between -90 and 90 degrees
+        latitude: f64,
+        /// The longitude that was given, between -180 and 180 degrees
+        longitude: f64,
+    },
+    /// The NoSunrise variant is used when the sun does not rise or does not set on the day at the location.
+    NoSunrise,
+    /// The WrongPattern variant is used when a format pattern has an unknown specifier.
+    WrongPattern {
+        /// The position of the `%` of the specifier, counted in characters from 0
+        position: usize,
+    },
+    /// The ParseError variant is used when a date could not be read from the input.
+    ParseError {
+        /// The position in the input where the reading failed, counted in characters from 0
+        position: usize,
+        /// The error of the part of the date at the position, if the part was read but was not valid
+        cause: Option<Box<DateError>>,
+    },
+}
+
+impl DateError {
+    /// Create the error of a part of the date that is out of range
+    pub(crate) fn out_of_range<T: Into<i64>>(field: DateField, value: T, min: T, max: T) -> Self {
+        DateError::OutOfRange {
+            field,
+            value: value.into(),
+            min: min.into(),
+            max: max.into(),
+        }
+    }
 }
 
 impl std::fmt::Display for DateError {
@@ -54,8 +161,16 @@ impl std::fmt::Display for DateError {
             DateError::WrongMonth(err) => {
                 write!(f, "DateError: The month in the date was wrong: {}", err)
             }
-            DateError::WrongDay => write!(f, "DateError: The day in the date was wrong"),
-            DateError::WrongYear => write!(f, "DateError: The year in the date was wrong"),
+            DateError::OutOfRange {
+                field,
+                value,
+                min,
+                max,
+            } => write!(
+                f,
+                "DateError: The {} {} is out of range, it should be between {} and {}",
+                field, value, min, max
+            ),
             DateError::NumToCharError => {
                 write!(f, "DateError: Failed to convert number to character")
             }
@@ -65,6 +180,90 @@ impl std::fmt::Display for DateError {
             DateError::ArithmeticError => {
                 write!(f, "DateError: Failed to perform arithmetic operation")
             }
+            DateError::DayOutOfMonth {
+                month, year, days, ..
+            } => {
+                let month = match month {
+                    Month::English(month) => month.to_string(),
+                    Month::Bengali(month) => format!("{:?}", month),
+                    Month::Unknown => "Unknown month".to_string(),
+                };
+                write!(f, "DateError: {} {} has only {} days", month, year, days)
+            }
+            DateError::WeekDayMismatch { given, actual } => {
+                write!(
+                    f,
+                    "DateError: The date falls on {}, not on {}",
+                    actual, given
+                )
+            }
+            DateError::SkippedDay { day, month, year } => {
+                write!(
+                    f,
+                    "DateError: {} {} {} was skipped by the switchover to the Gregorian calendar",
+                    day, month, year
+                )
+            }
+            DateError::WrongLocation {
+                latitude,
+                longitude,
+            } => {
+                write!(
+                    f,
+                    "DateError: The location ({}, {}) is out of range, the latitude should be between -90 and 90 and the longitude between -180 and 180",
+                    latitude, longitude
+                )
+            }
+            DateError::NoSunrise => {
+                write!(
+                    f,
+                    "DateError: The sun does not rise on the day at the location"
+                )
+            }
+            DateError::WrongPattern { position } => {
+                write!(
+                    f,
+                    "DateError: The pattern has an unknown specifier at position {}",
+                    position
+                )
+            }
+            DateError::ParseError { position, cause } => match cause {
+                Some(cause) => write!(
+                    f,
+                    "DateError: The input is not a valid date at position {}: {}",
+                    position, cause
+                ),
+                None => write!(
+                    f,
+                    "DateError: The input could not be read at position {}",
+                    position
+                ),
+            },
+        }
+    }
+}
+
+impl std::error::Error for DateError {
+    /// Get the error that caused the selected error
+    /// # Returns
+    /// * `Option<&(dyn std::error::Error + 'static)>` - The month, week day or casting error, or the cause of a parse error, if any
+    /// # Example
+    /// ```
+    /// use std::error::Error;
+    /// use ponjika::date::DateError;
+    /// use ponjika::months::MonthError;
+    /// let error = DateError::WrongMonth(MonthError::WrongRange);
+    /// assert_eq!(error.source().unwrap().to_string(), MonthError::WrongRange.to_string());
+    /// ```
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            DateError::WrongWeekDay(err) => Some(err),
+            DateError::WrongMonth(err) => Some(err),
+            DateError::CastingError(err) => Some(err),
+            DateError::ParseError {
+                cause: Some(cause), ..
+            } => Some(cause.as_ref()),
+            _ => None,
         }
     }
 }
@@ -74,11 +273,14 @@ type DateResult = Result<(String, String, String, String), DateError>;
 
 /// # `Date`: The enum for the dates.
 /// The enum variants are the English and Bengali dates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Date {
     /// The English variant is used to represent the English dates.
     English(EnglishDate),
     /// The Bengali variant is used to represent the Bengali dates.
     Bengali(BengaliDate),
+    /// The Julian variant is used to represent the English dates before the Gregorian reform.
+    Julian(JulianDate),
     /// The Unknown variant is used when the date is invalid.
     Unknown,
 }
@@ -100,9 +302,9 @@ impl Date {
     /// # Note
     /// * The function will return the English date
     /// * The function will return `None` if the date is not English
-    pub fn get_english_date(self) -> Option<EnglishDate> {
+    pub fn get_english_date(&self) -> Option<EnglishDate> {
         match self {
-            Date::English(date) => Some(date),
+            Date::English(date) => Some(*date),
             _ => None,
         }
     }
@@ -123,9 +325,32 @@ impl Date {
     /// # Note
     /// * The function will return the Bengali date
     /// * The function will return `None` if the date is not Bengali
-    pub fn get_bengali_date(self) -> Option<BengaliDate> {
+    pub fn get_bengali_date(&self) -> Option<BengaliDate> {
         match self {
-            Date::Bengali(date) => Some(date),
+            Date::Bengali(date) => Some(*date),
+            _ => None,
+        }
+    }
+
+    /// Get the Julian date from the selected date
+    /// # Returns
+    /// * `Option<JulianDate>` - The Julian date
+    /// # Example
+    /// ```
+    /// use ponjika::date::Date;
+    /// use ponjika::date::JulianDate;
+    /// use ponjika::months::EnglishMonths;
+    /// let date = JulianDate::create_date(4, EnglishMonths::October, 1582).unwrap();
+    /// let date = Date::Julian(date);
+    /// let julian_date = date.get_julian_date().unwrap();
+    /// assert_eq!(julian_date.get_date(), (4, 10, 1582));
+    /// ```
+    /// # Note
+    /// * The function will return the Julian date
+    /// * The function will return `None` if the date is not Julian
+    pub fn get_julian_date(&self) -> Option<JulianDate> {
+        match self {
+            Date::Julian(date) => Some(*date),
             _ => None,
         }
     }
@@ -160,10 +385,7 @@ impl Date {
                 date.year.to_string(),
             )),
             Date::Bengali(date) => Ok((
-                match date.get_day() {
-                    Ok(day) => day,
-                    Err(err) => return Err(err),
-                },
+                date.get_day()?,
                 match date.get_week_day() {
                     Ok(week_day) => week_day,
                     Err(err) => return Err(DateError::WrongWeekDay(err)),
@@ -172,10 +394,19 @@ impl Date {
                     Ok(month) => month,
                     Err(err) => return Err(DateError::WrongMonth(err)),
                 },
-                match date.get_year() {
-                    Ok(year) => year,
-                    Err(err) => return Err(err),
+                date.get_year()?,
+            )),
+            Date::Julian(date) => Ok((
+                date.day.to_string(),
+                match date.get_week_day() {
+                    Ok(week_day) => week_day,
+                    Err(err) => return Err(DateError::WrongWeekDay(err)),
+                },
+                match date.get_month() {
+                    Ok(month) => month,
+                    Err(err) => return Err(DateError::WrongMonth(err)),
                 },
+                date.year.to_string(),
             )),
             Date::Unknown => Err(DateError::UnknownDate),
         }
@@ -213,6 +444,7 @@ impl fmt::Display for Date {
                 date.get_month().unwrap(),
                 date.year
             ),
+            Date::Julian(date) => write!(f, "{}", date),
             Date::Unknown => write!(f, "Unknown date"),
         }
     }
@@ -220,7 +452,7 @@ impl fmt::Display for Date {
 
 /// # `EnglishDate`: The struct for the English date.
 /// The struct is used to represent the English date.
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct EnglishDate {
     day: u8,
     week_day: WeekDays,
@@ -230,46 +462,28 @@ pub struct EnglishDate {
 }
 
 impl EnglishDate {
-    fn is_valid_date(day: u8, month: u8, year: u16) -> Result<bool, DateError> {
-        if day < 1 || day > 31 {
-            return Err(DateError::WrongDay);
+    fn is_valid_date(day: u8, month: u8, year: u16) -> Result<(), DateError> {
+        if !(1..=31).contains(&day) {
+            return Err(DateError::out_of_range(DateField::Day, day, 1, 31));
         }
 
-        if year < 593 || year > 9999 {
-            return Err(DateError::WrongYear);
+        if !(593..=9999).contains(&year) {
+            return Err(DateError::out_of_range(DateField::Year, year, 593, 9999));
         }
 
-        match year % 4 {
-            0 => match month {
-                1 => Ok(day <= 31),
-                2 => Ok(day <= 29),
-                3 => Ok(day <= 31),
-                4 => Ok(day <= 30),
-                5 => Ok(day <= 31),
-                6 => Ok(day <= 30),
-                7 => Ok(day <= 31),
-                8 => Ok(day <= 31),
-                9 => Ok(day <= 30),
-                10 => Ok(day <= 31),
-                11 => Ok(day <= 30),
-                12 => Ok(day <= 31),
-                _ => Err(DateError::WrongMonth(MonthError::WrongRange)),
-            },
-            _ => match month {
-                1 => Ok(day <= 31),
-                2 => Ok(day <= 28),
-                3 => Ok(day <= 31),
-                4 => Ok(day <= 30),
-                5 => Ok(day <= 31),
-                6 => Ok(day <= 30),
-                7 => Ok(day <= 31),
-                8 => Ok(day <= 31),
-                9 => Ok(day <= 30),
-                10 => Ok(day <= 31),
-                11 => Ok(day <= 30),
-                12 => Ok(day <= 31),
-                _ => Err(DateError::WrongMonth(MonthError::WrongRange)),
-            },
+        let month = match EnglishMonths::get_month(month) {
+            Ok(month) => month,
+            Err(err) => return Err(DateError::WrongMonth(err)),
+        };
+        let days = month.days_in(year);
+        match day <= days {
+            true => Ok(()),
+            false => Err(DateError::DayOutOfMonth {
+                day,
+                month: Month::English(month),
+                year,
+                days,
+            }),
         }
     }
 
@@ -289,44 +503,14 @@ impl EnglishDate {
     /// ```
     /// # Note
     /// * The function will return the English date
-    /// * The function will return `DateError` if there is an error
+    /// * The function will return `DateError::OutOfRange` if the day is not between 1 and 31 or the year is not between 593 and 9999
+    /// * The function will return `DateError::DayOutOfMonth` if the month of the year has fewer days than the day
     pub fn create_date(day: u8, month: EnglishMonths, year: u16) -> Result<Self, DateError> {
         let month_index = month.map_to_index();
-        match Self::is_valid_date(day, month_index, year) {
-            Ok(valid) => {
-                if !valid {
-                    return Err(DateError::UnknownDate);
-                }
-            }
-            Err(err) => return Err(err),
-        }
+        Self::is_valid_date(day, month_index, year)?;
 
-        let week_day = match year {
-            593..=9999 => {
-                let date = match Utc.with_ymd_and_hms(
-                    year as i32,
-                    month_index as u32,
-                    day as u32,
-                    0,
-                    0,
-                    0,
-                ) {
-                    chrono::LocalResult::Single(date) => date,
-                    _ => return Err(DateError::UnknownDate),
-                };
-
-                match date.weekday() {
-                    Weekday::Mon => WeekDays::English(EnglishWeekDays::Monday),
-                    Weekday::Tue => WeekDays::English(EnglishWeekDays::Tuesday),
-                    Weekday::Wed => WeekDays::English(EnglishWeekDays::Wednesday),
-                    Weekday::Thu => WeekDays::English(EnglishWeekDays::Thursday),
-                    Weekday::Fri => WeekDays::English(EnglishWeekDays::Friday),
-                    Weekday::Sat => WeekDays::English(EnglishWeekDays::Saturday),
-                    Weekday::Sun => WeekDays::English(EnglishWeekDays::Sunday),
-                }
-            }
-            _ => return Err(DateError::WrongWeekDay(WeekDayError::FailedDateTimes)),
-        };
+        let fixed = rata_die::fixed_from_gregorian(day, month_index, year as i64);
+        let week_day = WeekDays::English(RataDie::new(fixed).get_english_week_day());
 
         Ok(EnglishDate {
             day,
@@ -354,6 +538,85 @@ impl EnglishDate {
         (self.day, self.month, self.year)
     }
 
+    /// Check whether a year is a leap year
+    /// # Arguments
+    /// * `year` - u16
+    /// # Returns
+    /// * `bool` - `true` if February of the year has 29 days
+    /// # Example
+    /// ```
+    /// use ponjika::date::EnglishDate;
+    /// assert!(EnglishDate::is_leap_year(2024));
+    /// assert!(EnglishDate::is_leap_year(2000));
+    /// assert!(!EnglishDate::is_leap_year(2100));
+    /// ```
+    /// # Note
+    /// * A year divisible by 100 is a leap year only if it is also divisible by 400
+    pub fn is_leap_year(year: u16) -> bool {
+        calendar::is_leap_year(year as i64)
+    }
+
+    /// Get the number of days of a year
+    /// # Arguments
+    /// * `year` - u16
+    /// # Returns
+    /// * `u16` - 366 for a leap year, 365 otherwise
+    /// # Example
+    /// ```
+    /// use ponjika::date::EnglishDate;
+    /// assert_eq!(EnglishDate::days_in_year(2100), 365);
+    /// ```
+    pub fn days_in_year(year: u16) -> u16 {
+        365 + Self::is_leap_year(year) as u16
+    }
+
+    /// Get the fixed day number of the selected date
+    /// # Returns
+    /// * `RataDie` - The fixed day number
+    /// # Example
+    /// ```
+    /// use ponjika::date::EnglishDate;
+    /// use ponjika::months::EnglishMonths;
+    /// let date = EnglishDate::create_date(1, EnglishMonths::January, 2021).unwrap();
+    /// assert_eq!(date.to_rata_die().get_day_number(), 737_791);
+    /// ```
+    pub fn to_rata_die(&self) -> RataDie {
+        RataDie::new(rata_die::fixed_from_gregorian(
+            self.day,
+            self.month,
+            self.year as i64,
+        ))
+    }
+
+    /// Create an English date from a fixed day number
+    /// # Arguments
+    /// * `rata_die` - RataDie
+    /// # Returns
+    /// * `EnglishDate` - The English date
+    /// # Example
+    /// ```
+    /// use ponjika::date::EnglishDate;
+    /// use ponjika::rata_die::RataDie;
+    /// let date = EnglishDate::from_rata_die(RataDie::new(737_791)).unwrap();
+    /// assert_eq!(date.get_date(), (1, 1, 2021));
+    /// ```
+    /// # Note
+    /// * The function will return `DateError::OutOfRange` with the `DateField::DayNumber` field if the day is before or after the supported years
+    pub fn from_rata_die(rata_die: RataDie) -> Result<Self, DateError> {
+        rata_die.check_range(rata_die::gregorian_days())?;
+        let (day, month, year) = rata_die::gregorian_from_fixed(rata_die.get_day_number());
+
+        let year: u16 = match year.try_into() {
+            Ok(year) => year,
+            Err(_) => return Err(DateError::out_of_range(DateField::Year, year, 593, 9999)),
+        };
+
+        match EnglishMonths::get_month(month) {
+            Ok(month) => Self::create_date(day, month, year),
+            Err(err) => Err(DateError::WrongMonth(err)),
+        }
+    }
+
     /// Get the week day of the selected date
     /// # Returns
     /// * `String` - The week day of the date
@@ -424,59 +687,236 @@ impl fmt::Display for EnglishDate {
     }
 }
 
+/// # `JulianDate`: The struct for the Julian date.
+/// The struct is used to represent the English dates written before the Gregorian reform.
+/// The months and week days are the same as in the Gregorian calendar, but every fourth year is a leap year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct JulianDate {
+    day: u8,
+    week_day: WeekDays,
+    month: u8,
+    month_name: Month,
+    year: u16,
+}
+
+impl JulianDate {
+    fn is_valid_date(day: u8, month: u8, year: u16) -> Result<(), DateError> {
+        if !(1..=31).contains(&day) {
+            return Err(DateError::out_of_range(DateField::Day, day, 1, 31));
+        }
+
+        if !(593..=9999).contains(&year) {
+            return Err(DateError::out_of_range(DateField::Year, year, 593, 9999));
+        }
+
+        let month = match EnglishMonths::get_month(month) {
+            Ok(month) => month,
+            Err(err) => return Err(DateError::WrongMonth(err)),
+        };
+        let days = rata_die::julian_days_in_month(month.map_to_index(), year as i64);
+        match day <= days {
+            true => Ok(()),
+            false => Err(DateError::DayOutOfMonth {
+                day,
+                month: Month::English(month),
+                year,
+                days,
+            }),
+        }
+    }
+
+    /// Create a Julian date
+    /// # Arguments
+    /// * `day` - u8
+    /// * `month` - EnglishMonths
+    /// * `year` - u16
+    /// # Returns
+    /// * `JulianDate` - The Julian date
+    /// # Example
+    /// ```
+    /// use ponjika::date::JulianDate;
+    /// use ponjika::months::EnglishMonths;
+    /// let date = JulianDate::create_date(29, EnglishMonths::February, 1500);
+    /// assert!(date.is_ok());
+    /// ```
+    /// # Note
+    /// * The function will return the Julian date
+    /// * The function will return `DateError::OutOfRange` if the day is not between 1 and 31 or the year is not between 593 and 9999
+    /// * The function will return `DateError::DayOutOfMonth` if the month of the year has fewer days than the day
+    pub fn create_date(day: u8, month: EnglishMonths, year: u16) -> Result<Self, DateError> {
+        let month_index = month.map_to_index();
+        Self::is_valid_date(day, month_index, year)?;
+
+        let fixed = rata_die::fixed_from_julian(day, month_index, year as i64);
+        let week_day = WeekDays::English(RataDie::new(fixed).get_english_week_day());
+
+        Ok(JulianDate {
+            day,
+            week_day,
+            month: month_index,
+            month_name: Month::English(month),
+            year,
+        })
+    }
+
+    /// Get the date in numbers of the selected date
+    /// # Returns
+    /// * `(u8, u8, u16)` - The day, month, and year of the date
+    /// # Example
+    /// ```
+    /// use ponjika::date::JulianDate;
+    /// use ponjika::months::EnglishMonths;
+    /// let date = JulianDate::create_date(4, EnglishMonths::October, 1582).unwrap();
+    /// assert_eq!(date.get_date(), (4, 10, 1582));
+    /// ```
+    pub fn get_date(&self) -> (u8, u8, u16) {
+        (self.day, self.month, self.year)
+    }
+
+    /// Get the fixed day number of the selected date
+    /// # Returns
+    /// * `RataDie` - The fixed day number
+    /// # Example
+    /// ```
+    /// use ponjika::date::{EnglishDate, JulianDate};
+    /// use ponjika::months::EnglishMonths;
+    /// let julian_date = JulianDate::create_date(4, EnglishMonths::October, 1582).unwrap();
+    /// let english_date = EnglishDate::create_date(15, EnglishMonths::October, 1582).unwrap();
+    /// assert_eq!(julian_date.to_rata_die().get_day_number() + 1, english_date.to_rata_die().get_day_number());
+    /// ```
+    pub fn to_rata_die(&self) -> RataDie {
+        RataDie::new(rata_die::fixed_from_julian(
+            self.day,
+            self.month,
+            self.year as i64,
+        ))
+    }
+
+    /// Create a Julian date from a fixed day number
+    /// # Arguments
+    /// * `rata_die` - RataDie
+    /// # Returns
+    /// * `JulianDate` - The Julian date
+    /// # Example
+    /// ```
+    /// use ponjika::date::JulianDate;
+    /// use ponjika::rata_die::RataDie;
+    /// let date = JulianDate::from_rata_die(RataDie::new(577_735)).unwrap();
+    /// assert_eq!(date.get_date(), (4, 10, 1582));
+    /// ```
+    /// # Note
+    /// * The function will return `DateError::OutOfRange` with the `DateField::DayNumber` field if the day is before or after the supported years
+    pub fn from_rata_die(rata_die: RataDie) -> Result<Self, DateError> {
+        rata_die.check_range(rata_die::julian_days())?;
+        let (day, month, year) = rata_die::julian_from_fixed(rata_die.get_day_number());
+
+        let year: u16 = match year.try_into() {
+            Ok(year) => year,
+            Err(_) => return Err(DateError::out_of_range(DateField::Year, year, 593, 9999)),
+        };
+
+        match EnglishMonths::get_month(month) {
+            Ok(month) => Self::create_date(day, month, year),
+            Err(err) => Err(DateError::WrongMonth(err)),
+        }
+    }
+
+    /// Get the week day of the selected date
+    /// # Returns
+    /// * `String` - The week day of the date
+    /// # Example
+    /// ```
+    /// use ponjika::date::JulianDate;
+    /// use ponjika::months::EnglishMonths;
+    /// let date = JulianDate::create_date(4, EnglishMonths::October, 1582).unwrap();
+    /// assert_eq!(date.get_week_day().unwrap(), "Thursday");
+    /// ```
+    /// # Note
+    /// * The function will return the week day of the date
+    /// * The function will return `WeekDayError` if there is an error
+    pub fn get_week_day(&self) -> Result<String, WeekDayError> {
+        self.week_day.get_week_day()
+    }
+
+    /// Get the month of the selected date
+    /// # Returns
+    /// * `String` - The month of the date
+    /// # Example
+    /// ```
+    /// use ponjika::date::JulianDate;
+    /// use ponjika::months::EnglishMonths;
+    /// let date = JulianDate::create_date(4, EnglishMonths::October, 1582).unwrap();
+    /// assert_eq!(date.get_month().unwrap(), "October");
+    /// ```
+    /// # Note
+    /// * The function will return the month of the date
+    /// * The function will return `MonthError` if there is an error
+    pub fn get_month(&self) -> Result<String, MonthError> {
+        self.month_name.get_month_name()
+    }
+}
+
+impl fmt::Display for JulianDate {
+    /// Display the date
+    /// # Returns
+    /// * `fmt::Result` - The date
+    /// # Example
+    /// ```
+    /// use ponjika::date::JulianDate;
+    /// use ponjika::months::EnglishMonths;
+    /// let date = JulianDate::create_date(4, EnglishMonths::October, 1582).unwrap();
+    /// assert_eq!(date.to_string(), "Thursday, 4 October 1582");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{1}, {0} {2} {3}",
+            self.day,
+            self.get_week_day().unwrap(),
+            self.get_month().unwrap(),
+            self.year
+        )
+    }
+}
+
 /// # `BengaliDate`: The struct for the Bengali date.
 /// The struct is used to represent the Bengali date.
-#[derive(Debug)]
+/// The date keeps the `CalendarVariant` whose rules it follows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct BengaliDate {
     day: u8,
     week_day: WeekDays,
     month: u8,
     month_name: Month,
     year: u16,
+    variant: CalendarVariant,
 }
 
 impl BengaliDate {
-    fn is_valid_date(day: u8, month: u8, year: u16) -> Result<bool, DateError> {
-        if day < 1 || day > 31 {
-            return Err(DateError::WrongDay);
+    fn is_valid_date(
+        day: u8,
+        month: u8,
+        year: u16,
+        variant: CalendarVariant,
+    ) -> Result<(), DateError> {
+        if !(1..=32).contains(&day) {
+            return Err(DateError::out_of_range(DateField::Day, day, 1, 32));
         }
 
-        if year < 1 || year > 8568 {
-            return Err(DateError::WrongYear);
+        if !(1..=9406).contains(&year) {
+            return Err(DateError::out_of_range(DateField::Year, year, 1, 9406));
         }
 
-        match month % 4 {
-            // leap year
-            0 => match month {
-                1 => Ok(day <= 31),
-                2 => Ok(day <= 31),
-                3 => Ok(day <= 31),
-                4 => Ok(day <= 31),
-                5 => Ok(day <= 31),
-                6 => Ok(day <= 30),
-                7 => Ok(day <= 30),
-                8 => Ok(day <= 30),
-                9 => Ok(day <= 30),
-                10 => Ok(day <= 30),
-                11 => Ok(day <= 30),
-                12 => Ok(day <= 30),
-                _ => Err(DateError::WrongMonth(MonthError::WrongRange)),
-            },
-            _ => match month {
-                1 => Ok(day <= 31),
-                2 => Ok(day <= 31),
-                3 => Ok(day <= 31),
-                4 => Ok(day <= 31),
-                5 => Ok(day <= 31),
-                6 => Ok(day <= 30),
-                7 => Ok(day <= 30),
-                8 => Ok(day <= 30),
-                9 => Ok(day <= 30),
-                10 => Ok(day <= 30),
-                11 => Ok(day <= 31),
-                12 => Ok(day <= 30),
-                _ => Err(DateError::WrongMonth(MonthError::WrongRange)),
-            },
+        // Falgun has a leap day when it falls in February of a Gregorian leap year
+        match variant.days_in_month(month, year as i64) {
+            Some(days) if day <= days => Ok(()),
+            Some(days) => Err(DateError::DayOutOfMonth {
+                day,
+                month: Month::Bengali(BengaliMonths::get_month(month).unwrap()),
+                year,
+                days,
+            }),
+            None => Err(DateError::out_of_range(DateField::Month, month, 1, 12)),
         }
     }
 
@@ -493,37 +933,65 @@ impl BengaliDate {
     /// use ponjika::date::BengaliDate;
     /// use ponjika::months::BengaliMonths;
     /// use ponjika::days::BengaliWeekDays;
+    /// let date = BengaliDate::create_date_with_weekday(1, BengaliWeekDays::Budhbar, BengaliMonths::Baishakh, 1428);
+    /// assert!(date.is_ok());
     /// let date = BengaliDate::create_date_with_weekday(1, BengaliWeekDays::Shonibar, BengaliMonths::Baishakh, 1428);
-    /// println!("{:?}", date);
+    /// assert!(date.is_err());
     /// ```
     /// # Note
     /// * The function will return the Bengali date
-    /// * The function will return `DateError` if the date is invalid
+    /// * The function will return `DateError::OutOfRange` if the day is not between 1 and 32 or the year is not between 1 and 9406
+    /// * The function will return `DateError::DayOutOfMonth` if the month of the year has fewer days than the day
+    /// * The function will return `DateError::WeekDayMismatch` if the date does not fall on the week day
     pub fn create_date_with_weekday(
         day: u8,
         week_day: BengaliWeekDays,
         month: BengaliMonths,
         year: u16,
+    ) -> Result<Self, DateError> {
+        Self::create_date_with_weekday_and_variant(
+            day,
+            week_day,
+            month,
+            year,
+            CalendarVariant::default(),
+        )
+    }
+
+    pub(crate) fn create_date_with_weekday_and_variant(
+        day: u8,
+        week_day: BengaliWeekDays,
+        month: BengaliMonths,
+        year: u16,
+        variant: CalendarVariant,
     ) -> Result<Self, DateError> {
         let month_index = month.map_to_index();
-        match Self::is_valid_date(day, month_index, year) {
-            Ok(valid) => {
-                if !valid {
-                    return Err(DateError::UnknownDate);
-                }
-            }
-            Err(err) => return Err(err),
+        Self::is_valid_date(day, month_index, year, variant)?;
+
+        let actual = Self::week_day_of(day, month_index, year, variant);
+        if week_day != actual {
+            return Err(DateError::WeekDayMismatch {
+                given: week_day,
+                actual,
+            });
         }
 
         Ok(BengaliDate {
             day,
             week_day: WeekDays::Bengali(week_day),
             month: month_index,
-            month_name: Month::Bengali(BengaliMonths::get_month(month_index).unwrap()),
+            month_name: Month::Bengali(month),
             year,
+            variant,
         })
     }
 
+    /// The week day of a valid Bengali date
+    fn week_day_of(day: u8, month: u8, year: u16, variant: CalendarVariant) -> BengaliWeekDays {
+        let fixed = calendar::fixed_from_bengali(day, month, year as i64, variant);
+        RataDie::new(fixed).get_bengali_week_day()
+    }
+
     /// Create a Bengali date
     /// # Arguments
     /// * `day` - u8
@@ -540,24 +1008,51 @@ impl BengaliDate {
     /// ```
     /// # Note
     /// * The function will return the Bengali date
-    /// * The function will return `DateError` if the date is invalid
+    /// * The function will return `DateError::OutOfRange` if the day is not between 1 and 32 or the year is not between 1 and 9406
+    /// * The function will return `DateError::DayOutOfMonth` if the month of the year has fewer days than the day
+    /// * The function will use the `CalendarVariant::Revised2019` rules
     pub fn create_date(day: u8, month: BengaliMonths, year: u16) -> Result<Self, DateError> {
+        Self::create_date_with_variant(day, month, year, CalendarVariant::default())
+    }
+
+    /// Create a Bengali date that follows the rules of a calendar variant
+    /// # Arguments
+    /// * `day` - u8
+    /// * `month` - BengaliMonths
+    /// * `year` - u16
+    /// * `variant` - CalendarVariant
+    /// # Returns
+    /// * `Date` - The Bengali date
+    /// # Example
+    /// ```
+    /// use ponjika::calendar::CalendarVariant;
+    /// use ponjika::date::BengaliDate;
+    /// use ponjika::months::BengaliMonths;
+    /// let date = BengaliDate::create_date_with_variant(31, BengaliMonths::Ashwin, 1430, CalendarVariant::Revised2019);
+    /// assert!(date.is_ok());
+    /// let date = BengaliDate::create_date_with_variant(31, BengaliMonths::Ashwin, 1420, CalendarVariant::Revised1987);
+    /// assert!(date.is_err());
+    /// ```
+    /// # Note
+    /// * The function will return the Bengali date
+    /// * The function will return `DateError::OutOfRange` if the day is not between 1 and 32 or the year is not between 1 and 9406
+    /// * The function will return `DateError::DayOutOfMonth` if the month of the year has fewer days than the day
+    pub fn create_date_with_variant(
+        day: u8,
+        month: BengaliMonths,
+        year: u16,
+        variant: CalendarVariant,
+    ) -> Result<Self, DateError> {
         let month_index = month.map_to_index();
-        match Self::is_valid_date(day, month_index, year) {
-            Ok(valid) => {
-                if !valid {
-                    return Err(DateError::UnknownDate);
-                }
-            }
-            Err(_) => return Err(DateError::UnknownDate),
-        }
+        Self::is_valid_date(day, month_index, year, variant)?;
 
         Ok(BengaliDate {
             day,
-            week_day: WeekDays::Bengali(BengaliWeekDays::UnImplemented),
+            week_day: WeekDays::Bengali(Self::week_day_of(day, month_index, year, variant)),
             month: month_index,
             month_name: Month::Bengali(month),
             year,
+            variant,
         })
     }
 
@@ -576,6 +1071,155 @@ impl BengaliDate {
         (self.day, self.month, self.year)
     }
 
+    /// Get the number of days of a Bengali year
+    /// # Arguments
+    /// * `year` - u16
+    /// # Returns
+    /// * `u16` - The number of days
+    /// # Example
+    /// ```
+    /// use ponjika::date::BengaliDate;
+    /// assert_eq!(BengaliDate::days_in_year(1430), 366);
+    /// assert_eq!(BengaliDate::days_in_year(1431), 365);
+    /// ```
+    /// # Note
+    /// * The function will use the `CalendarVariant::Revised2019` rules
+    pub fn days_in_year(year: u16) -> u16 {
+        Self::days_in_year_with_variant(year, CalendarVariant::default())
+    }
+
+    /// Get the number of days of a Bengali year in a calendar variant
+    /// # Arguments
+    /// * `year` - u16
+    /// * `variant` - CalendarVariant
+    /// # Returns
+    /// * `u16` - The number of days
+    /// # Example
+    /// ```
+    /// use ponjika::calendar::CalendarVariant;
+    /// use ponjika::date::BengaliDate;
+    /// assert_eq!(BengaliDate::days_in_year_with_variant(1431, CalendarVariant::Traditional), 366);
+    /// ```
+    pub fn days_in_year_with_variant(year: u16, variant: CalendarVariant) -> u16 {
+        (1..=12)
+            .map(|month| {
+                variant
+                    .days_in_month(month, year as i64)
+                    .unwrap_or_default() as u16
+            })
+            .sum()
+    }
+
+    /// Check whether a Bengali year is a leap year
+    /// # Arguments
+    /// * `year` - u16
+    /// # Returns
+    /// * `bool` - `true` if the year has 366 days
+    /// # Example
+    /// ```
+    /// use ponjika::date::BengaliDate;
+    /// assert!(BengaliDate::is_leap_year(1430));
+    /// assert!(!BengaliDate::is_leap_year(1431));
+    /// ```
+    /// # Note
+    /// * The function will use the `CalendarVariant::Revised2019` rules
+    /// * Falgun has a leap day when it falls in February of a Gregorian leap year
+    pub fn is_leap_year(year: u16) -> bool {
+        Self::is_leap_year_with_variant(year, CalendarVariant::default())
+    }
+
+    /// Check whether a Bengali year is a leap year in a calendar variant
+    /// # Arguments
+    /// * `year` - u16
+    /// * `variant` - CalendarVariant
+    /// # Returns
+    /// * `bool` - `true` if the year has 366 days
+    /// # Example
+    /// ```
+    /// use ponjika::calendar::CalendarVariant;
+    /// use ponjika::date::BengaliDate;
+    /// assert!(BengaliDate::is_leap_year_with_variant(1380, CalendarVariant::Reformed1966));
+    /// ```
+    /// # Note
+    /// * A traditional year has 365 or 366 days depending on the movement of the sun
+    pub fn is_leap_year_with_variant(year: u16, variant: CalendarVariant) -> bool {
+        Self::days_in_year_with_variant(year, variant) == 366
+    }
+
+    /// Get the calendar variant of the selected date
+    /// # Returns
+    /// * `CalendarVariant` - The rule set the date follows
+    /// # Example
+    /// ```
+    /// use ponjika::calendar::CalendarVariant;
+    /// use ponjika::date::BengaliDate;
+    /// use ponjika::months::BengaliMonths;
+    /// let date = BengaliDate::create_date(1, BengaliMonths::Baishakh, 1428).unwrap();
+    /// assert_eq!(date.get_variant(), CalendarVariant::Revised2019);
+    /// ```
+    pub fn get_variant(&self) -> CalendarVariant {
+        self.variant
+    }
+
+    /// Get the fixed day number of the selected date
+    /// # Returns
+    /// * `RataDie` - The fixed day number
+    /// # Example
+    /// ```
+    /// use ponjika::date::BengaliDate;
+    /// use ponjika::months::BengaliMonths;
+    /// let date = BengaliDate::create_date(1, BengaliMonths::Baishakh, 1428).unwrap();
+    /// assert_eq!(date.to_rata_die().get_day_number(), 737_894);
+    /// ```
+    /// # Note
+    /// * The function will use the calendar variant of the date
+    pub fn to_rata_die(&self) -> RataDie {
+        RataDie::new(calendar::fixed_from_bengali(
+            self.day,
+            self.month,
+            self.year as i64,
+            self.variant,
+        ))
+    }
+
+    /// Create a Bengali date from a fixed day number
+    /// # Arguments
+    /// * `rata_die` - RataDie
+    /// * `variant` - CalendarVariant
+    /// # Returns
+    /// * `BengaliDate` - The Bengali date with its week day
+    /// # Example
+    /// ```
+    /// use ponjika::calendar::CalendarVariant;
+    /// use ponjika::date::BengaliDate;
+    /// use ponjika::rata_die::RataDie;
+    /// let date = BengaliDate::from_rata_die(RataDie::new(737_894), CalendarVariant::Revised2019).unwrap();
+    /// assert_eq!(date.get_date(), (1, 1, 1428));
+    /// assert_eq!(date.get_week_day().unwrap(), "বুধবার");
+    /// ```
+    /// # Note
+    /// * The function will return `DateError::OutOfRange` with the `DateField::DayNumber` field if the day is before or after the supported years
+    pub fn from_rata_die(rata_die: RataDie, variant: CalendarVariant) -> Result<Self, DateError> {
+        rata_die.check_range(calendar::bengali_days(variant))?;
+        let (day, month, year) = calendar::bengali_from_fixed(rata_die.get_day_number(), variant);
+
+        let year: u16 = match year.try_into() {
+            Ok(year) => year,
+            Err(_) => return Err(DateError::out_of_range(DateField::Year, year, 1, 9406)),
+        };
+
+        match BengaliMonths::get_month(month) {
+            Ok(month) => Self::create_date_with_weekday_and_variant(
+                day,
+                rata_die.get_bengali_week_day(),
+                month,
+                year,
+                variant,
+            ),
+            Err(err) => Err(DateError::WrongMonth(err)),
+        }
+    }
+
     /// Get the day of the selected date
     /// # Returns
     /// * `Result<String>` - The day of the date
@@ -589,17 +1233,9 @@ impl BengaliDate {
     /// ```
     /// # Note
     /// * The function will return the day of the date
-    /// * The function will return "DateError: Failed to convert number to character" if the day is invalid
+    /// * The day is written with `BengaliNumeral::to_bengali`, so the function always returns `Ok`
     pub fn get_day(&self) -> Result<String, DateError> {
-        let bengali_digits = ['০', '১', '২', '৩', '৪', '৫', '৬', '৭', '৮', '৯'];
-        self.day
-            .to_string()
-            .chars()
-            .map(|c| match c.to_digit(10) {
-                Some(digit) => Ok(bengali_digits[digit as usize]),
-                None => return Err(DateError::NumToCharError),
-            })
-            .collect()
+        Ok(self.day.to_bengali())
     }
 
     /// Get the week day of the selected date
@@ -611,10 +1247,7 @@ impl BengaliDate {
     /// use ponjika::months::BengaliMonths;
     /// let date = BengaliDate::create_date(1, BengaliMonths::Baishakh, 1428).unwrap();
     /// let week_day = date.get_week_day();
-    /// match week_day {
-    ///    Ok(week_day) => println!("{}", week_day),
-    ///    Err(err) => println!("{}", err),
-    /// }
+    /// assert_eq!(week_day.unwrap(), "বুধবার");
     /// ```
     /// # Note
     /// * The function will return the week day of the date
@@ -654,18 +1287,9 @@ impl BengaliDate {
     /// ```
     /// # Note
     /// * The function will return the year of the date
-    /// * The function will return "DateError: Failed to convert number to character" if the year is invalid
+    /// * The year is written with `BengaliNumeral::to_bengali`, so the function always returns `Ok`
     pub fn get_year(&self) -> Result<String, DateError> {
-        let bengali_digits = ['০', '১', '২', '৩', '৪', '৫', '৬', '৭', '৮', '৯'];
-
-        self.year
-            .to_string()
-            .chars()
-            .map(|c| match c.to_digit(10) {
-                Some(digit) => Ok(bengali_digits[digit as usize]),
-                None => return Err(DateError::NumToCharError),
-            })
-            .collect()
+        Ok(self.year.to_bengali())
     }
 }
 
@@ -678,26 +1302,114 @@ impl fmt::Display for BengaliDate {
     /// use ponjika::date::BengaliDate;
     /// use ponjika::months::BengaliMonths;
     /// let date = BengaliDate::create_date(1, BengaliMonths::Baishakh, 1428).unwrap();
-    /// println!("{}", date.to_string());
+    /// assert_eq!(date.to_string(), "বুধবার, ১ বৈশাখ ১৪২৮");
     /// ```
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        if self.week_day == WeekDays::Bengali(BengaliWeekDays::UnImplemented) {
-            write!(
-                f,
-                "{0} {1} {2}",
-                self.get_day().unwrap(),
-                self.get_month().unwrap(),
-                self.get_year().unwrap()
-            )
-        } else {
-            write!(
-                f,
-                "{1}, {0} {2} {3}",
-                self.get_day().unwrap(),
-                self.get_week_day().unwrap(),
-                self.get_month().unwrap(),
-                self.get_year().unwrap()
-            )
-        }
+        write!(
+            f,
+            "{1}, {0} {2} {3}",
+            self.get_day().unwrap(),
+            self.get_week_day().unwrap(),
+            self.get_month().unwrap(),
+            self.get_year().unwrap()
+        )
     }
 }
+
+impl Ord for EnglishDate {
+    /// Compare the dates chronologically
+    /// # Example
+    /// ```
+    /// use ponjika::date::EnglishDate;
+    /// use ponjika::months::EnglishMonths;
+    /// let first = EnglishDate::create_date(31, EnglishMonths::December, 2023).unwrap();
+    /// let second = EnglishDate::create_date(1, EnglishMonths::January, 2024).unwrap();
+    /// assert!(first < second);
+    /// ```
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.to_rata_die().cmp(&other.to_rata_die())
+    }
+}
+
+impl PartialOrd for EnglishDate {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for JulianDate {
+    /// Compare the dates chronologically
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.to_rata_die().cmp(&other.to_rata_die())
+    }
+}
+
+impl PartialOrd for JulianDate {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BengaliDate {
+    /// Compare the dates chronologically
+    /// # Example
+    /// ```
+    /// use ponjika::calendar::CalendarVariant;
+    /// use ponjika::date::BengaliDate;
+    /// use ponjika::months::BengaliMonths;
+    /// let first = BengaliDate::create_date(30, BengaliMonths::Chaitra, 1430).unwrap();
+    /// let second = BengaliDate::create_date(1, BengaliMonths::Baishakh, 1431).unwrap();
+    /// assert!(first < second);
+    ///
+    /// // 1 Baishakh 1432 is a day later in West Bengal
+    /// let bangladesh = BengaliDate::create_date(1, BengaliMonths::Baishakh, 1432).unwrap();
+    /// let west_bengal = BengaliDate::create_date_with_variant(1, BengaliMonths::Baishakh, 1432, CalendarVariant::Traditional).unwrap();
+    /// assert!(bangladesh < west_bengal);
+    /// ```
+    /// # Note
+    /// * Dates of different variants on the same day are ordered by the variant, so they are not equal
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        (self.to_rata_die(), self.variant).cmp(&(other.to_rata_die(), other.variant))
+    }
+}
+
+impl PartialOrd for BengaliDate {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Compare two kinds of dates by the day they represent
+macro_rules! compare_by_day {
+    ($first:ty, $second:ty) => {
+        impl PartialEq<$second> for $first {
+            fn eq(&self, other: &$second) -> bool {
+                self.to_rata_die() == other.to_rata_die()
+            }
+        }
+
+        impl PartialEq<$first> for $second {
+            fn eq(&self, other: &$first) -> bool {
+                self.to_rata_die() == other.to_rata_die()
+            }
+        }
+
+        impl PartialOrd<$second> for $first {
+            fn partial_cmp(&self, other: &$second) -> Option<std::cmp::Ordering> {
+                Some(self.to_rata_die().cmp(&other.to_rata_die()))
+            }
+        }
+
+        impl PartialOrd<$first> for $second {
+            fn partial_cmp(&self, other: &$first) -> Option<std::cmp::Ordering> {
+                Some(self.to_rata_die().cmp(&other.to_rata_die()))
+            }
+        }
+    };
+}
+
+compare_by_day!(EnglishDate, BengaliDate);
+compare_by_day!(EnglishDate, JulianDate);
+compare_by_day!(JulianDate, BengaliDate);
+compare_by_day!(DualDate, EnglishDate);
+compare_by_day!(DualDate, BengaliDate);
@@ -8,7 +8,7 @@ use std::fmt;
 
 /// # `WeekDayError`: The error enum for the week days.
 /// The enum variants are the error messages for the week days.
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum WeekDayError {
     /// The UnknownWeekDays variant is used when the week day is neither English nor Bengali.
     UnknownWeekDays,
@@ -34,12 +34,14 @@ impl fmt::Display for WeekDayError {
     }
 }
 
+impl std::error::Error for WeekDayError {}
+
 // Use the Result type to handle the error for the week days
 type Result<T> = std::result::Result<T, WeekDayError>;
 
 /// # `WeekDays`: The enum for the week days.
 /// The enum variants are the English and Bengali week days.
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum WeekDays {
     /// The English variant is used to represent the English week days.
     English(EnglishWeekDays),
@@ -75,7 +77,7 @@ impl WeekDays {
 
 /// # `EnglishWeekDays`: The enum for the English week days.
 /// The enum variants are the English week days.
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum EnglishWeekDays {
     Sunday,
     Monday,
@@ -138,6 +140,27 @@ impl EnglishWeekDays {
             _ => Err(WeekDayError::UnknownWeekDays),
         }
     }
+
+    /// Get the short name of the selected week day
+    /// # Returns
+    /// * `&str` - The first three letters of the week day name
+    /// # Example
+    /// ```
+    /// use ponjika::days::EnglishWeekDays;
+    /// assert_eq!(EnglishWeekDays::Saturday.get_short_name(), "Sat");
+    /// ```
+    pub fn get_short_name(&self) -> &str {
+        match self {
+            EnglishWeekDays::Sunday => "Sun",
+            EnglishWeekDays::Monday => "Mon",
+            EnglishWeekDays::Tuesday => "Tue",
+            EnglishWeekDays::Wednesday => "Wed",
+            EnglishWeekDays::Thursday => "Thu",
+            EnglishWeekDays::Friday => "Fri",
+            EnglishWeekDays::Saturday => "Sat",
+            EnglishWeekDays::UnImplemented => "unimplemented",
+        }
+    }
 }
 
 impl fmt::Display for EnglishWeekDays {
@@ -167,7 +190,7 @@ impl fmt::Display for EnglishWeekDays {
 
 /// # `BengaliWeekDays`: The enum for the Bengali week days.
 /// The enum variants are the Bengali week days.
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum BengaliWeekDays {
     Robibar,
     Sombar,
@@ -230,6 +253,45 @@ impl BengaliWeekDays {
             _ => Err(WeekDayError::UnknownWeekDays),
         }
     }
+
+    /// Get the position of the week day, counted from 0 for Robibar (Sunday)
+    /// # Returns
+    /// * `Result<i64>` - The position, the same as a fixed day number modulo 7
+    /// # Note
+    /// * The function will return "WeekDayError: Unknown week days" if the week day is `UnImplemented`
+    pub(crate) fn get_day_number(&self) -> Result<i64> {
+        match self {
+            BengaliWeekDays::Robibar => Ok(0),
+            BengaliWeekDays::Sombar => Ok(1),
+            BengaliWeekDays::Mongolbar => Ok(2),
+            BengaliWeekDays::Budhbar => Ok(3),
+            BengaliWeekDays::Brihoshpotibar => Ok(4),
+            BengaliWeekDays::Shukrobar => Ok(5),
+            BengaliWeekDays::Shonibar => Ok(6),
+            BengaliWeekDays::UnImplemented => Err(WeekDayError::UnknownWeekDays),
+        }
+    }
+
+    /// Get the short name of the selected week day
+    /// # Returns
+    /// * `&str` - The week day name without "বার"
+    /// # Example
+    /// ```
+    /// use ponjika::days::BengaliWeekDays;
+    /// assert_eq!(BengaliWeekDays::Shonibar.get_short_name(), "শনি");
+    /// ```
+    pub fn get_short_name(&self) -> &str {
+        match self {
+            BengaliWeekDays::Robibar => "রবি",
+            BengaliWeekDays::Sombar => "সোম",
+            BengaliWeekDays::Mongolbar => "মঙ্গল",
+            BengaliWeekDays::Budhbar => "বুধ",
+            BengaliWeekDays::Brihoshpotibar => "বৃহঃ",
+            BengaliWeekDays::Shukrobar => "শুক্র",
+            BengaliWeekDays::Shonibar => "শনি",
+            BengaliWeekDays::UnImplemented => "unimplemented",
+        }
+    }
 }
 
 impl fmt::Display for BengaliWeekDays {
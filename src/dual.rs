@@ -0,0 +1,300 @@
+//! # Dual: The Bengali and the English date of the same day
+//! The `dual` module provides the `DualDate` struct, which holds the Bengali and the English date of a day together.
+//! A `DualDate` is built from either side, and the arithmetic keeps both sides on the same day.
+
+use std::fmt;
+use std::ops::{Add, Sub};
+
+use crate::arithmetic::{Days, Overflow};
+use crate::calendar::CalendarVariant;
+use crate::date::{BengaliDate, Date, DateError, EnglishDate};
+use crate::rata_die::RataDie;
+
+/// # `DualDate`: The Bengali and the English date of the same day.
+/// The struct is used to show both calendars without converting by hand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DualDate {
+    bengali_date: BengaliDate,
+    english_date: EnglishDate,
+}
+
+impl DualDate {
+    /// Create a dual date from a Bengali date
+    /// # Arguments
+    /// * `bengali_date` - BengaliDate
+    /// # Returns
+    /// * `Result<DualDate, DateError>` - The dual date
+    /// # Example
+    /// ```
+    /// use ponjika::date::BengaliDate;
+    /// use ponjika::dual::DualDate;
+    /// use ponjika::months::BengaliMonths;
+    /// let date = BengaliDate::create_date(1, BengaliMonths::Baishakh, 1431).unwrap();
+    /// let date = DualDate::from_bengali(date).unwrap();
+    /// assert_eq!(date.get_english_date().get_date(), (14, 4, 2024));
+    /// ```
+    /// # Note
+    /// * The English date follows the calendar variant of the Bengali date
+    /// * The function will return `DateError::OutOfRange` if the English year is out of the supported range
+    pub fn from_bengali(bengali_date: BengaliDate) -> Result<Self, DateError> {
+        Ok(DualDate {
+            bengali_date,
+            english_date: EnglishDate::from_rata_die(bengali_date.to_rata_die())?,
+        })
+    }
+
+    /// Create a dual date from an English date
+    /// # Arguments
+    /// * `english_date` - EnglishDate
+    /// # Returns
+    /// * `Result<DualDate, DateError>` - The dual date
+    /// # Example
+    /// ```
+    /// use ponjika::date::EnglishDate;
+    /// use ponjika::dual::DualDate;
+    /// use ponjika::months::EnglishMonths;
+    /// let date = EnglishDate::create_date(14, EnglishMonths::April, 2024).unwrap();
+    /// let date = DualDate::from_english(date).unwrap();
+    /// assert_eq!(date.get_bengali_date().get_date(), (1, 1, 1431));
+    /// ```
+    /// # Note
+    /// * The function will use the `CalendarVariant::Revised2019` rules
+    /// * The function will return `DateError::OutOfRange` if the Bengali year is out of the supported range
+    pub fn from_english(english_date: EnglishDate) -> Result<Self, DateError> {
+        Self::from_english_with_variant(english_date, CalendarVariant::default())
+    }
+
+    /// Create a dual date from an English date in a calendar variant
+    /// # Arguments
+    /// * `english_date` - EnglishDate
+    /// * `variant` - CalendarVariant
+    /// # Returns
+    /// * `Result<DualDate, DateError>` - The dual date
+    /// # Example
+    /// ```
+    /// use ponjika::calendar::CalendarVariant;
+    /// use ponjika::date::EnglishDate;
+    /// use ponjika::dual::DualDate;
+    /// use ponjika::months::EnglishMonths;
+    /// let date = EnglishDate::create_date(14, EnglishMonths::April, 2025).unwrap();
+    /// let date = DualDate::from_english_with_variant(date, CalendarVariant::Traditional).unwrap();
+    /// assert_eq!(date.get_bengali_date().get_date(), (31, 12, 1431));
+    /// ```
+    /// # Note
+    /// * The function will return `DateError::OutOfRange` if the Bengali year is out of the supported range
+    pub fn from_english_with_variant(
+        english_date: EnglishDate,
+        variant: CalendarVariant,
+    ) -> Result<Self, DateError> {
+        Ok(DualDate {
+            bengali_date: BengaliDate::from_rata_die(english_date.to_rata_die(), variant)?,
+            english_date,
+        })
+    }
+
+    /// Get the Bengali date of the selected day
+    /// # Returns
+    /// * `BengaliDate` - The Bengali date
+    pub fn get_bengali_date(&self) -> BengaliDate {
+        self.bengali_date
+    }
+
+    /// Get the English date of the selected day
+    /// # Returns
+    /// * `EnglishDate` - The English date
+    pub fn get_english_date(&self) -> EnglishDate {
+        self.english_date
+    }
+
+    /// Get the calendar variant of the Bengali date
+    /// # Returns
+    /// * `CalendarVariant` - The rule set the Bengali date follows
+    pub fn get_variant(&self) -> CalendarVariant {
+        self.bengali_date.get_variant()
+    }
+
+    /// Get the fixed day number of the selected day
+    /// # Returns
+    /// * `RataDie` - The fixed day number
+    pub fn to_rata_die(&self) -> RataDie {
+        self.english_date.to_rata_die()
+    }
+
+    /// Add days to the selected date
+    /// # Arguments
+    /// * `days` - i64, can be negative
+    /// # Returns
+    /// * `Result<DualDate, DateError>` - The new date on both sides
+    /// # Example
+    /// ```
+    /// use ponjika::date::EnglishDate;
+    /// use ponjika::dual::DualDate;
+    /// use ponjika::months::EnglishMonths;
+    /// let date = EnglishDate::create_date(13, EnglishMonths::April, 2024).unwrap();
+    /// let date = DualDate::from_english(date).unwrap().add_days(1).unwrap();
+    /// assert_eq!(date.get_bengali_date().get_date(), (1, 1, 1431));
+    /// assert_eq!(date.get_english_date().get_date(), (14, 4, 2024));
+    /// ```
+    /// # Note
+    /// * The function will return `DateError::OutOfRange` if the year of the new date is out of the supported range
+    /// * The function will return `DateError::ArithmeticError` if the number of days overflows
+    pub fn add_days(&self, days: i64) -> Result<Self, DateError> {
+        Self::from_bengali(self.bengali_date.add_days(days)?)
+    }
+
+    /// Subtract days from the selected date
+    /// # Arguments
+    /// * `days` - i64, can be negative
+    /// # Returns
+    /// * `Result<DualDate, DateError>` - The new date on both sides
+    /// # Note
+    /// * The function will return `DateError::OutOfRange` if the year of the new date is out of the supported range
+    /// * The function will return `DateError::ArithmeticError` if the number of days overflows
+    pub fn sub_days(&self, days: i64) -> Result<Self, DateError> {
+        Self::from_bengali(self.bengali_date.sub_days(days)?)
+    }
+
+    /// Add Bengali months to the selected date
+    /// # Arguments
+    /// * `months` - i64, can be negative
+    /// * `overflow` - Overflow, the policy for the days that do not exist in the new Bengali month
+    /// # Returns
+    /// * `Result<DualDate, DateError>` - The new date on both sides
+    /// # Example
+    /// ```
+    /// use ponjika::arithmetic::Overflow;
+    /// use ponjika::date::BengaliDate;
+    /// use ponjika::dual::DualDate;
+    /// use ponjika::months::BengaliMonths;
+    /// let date = BengaliDate::create_date(1, BengaliMonths::Baishakh, 1431).unwrap();
+    /// let date = DualDate::from_bengali(date).unwrap().add_bengali_months(1, Overflow::Clamp).unwrap();
+    /// assert_eq!(date.get_english_date().get_date(), (15, 5, 2024));
+    /// ```
+    /// # Note
+    /// * The function will return `DateError::DayOutOfMonth` if the day does not exist and the policy is `Overflow::Reject`
+    /// * The function will return `DateError::OutOfRange` if the year of the new date is out of the supported range
+    pub fn add_bengali_months(&self, months: i64, overflow: Overflow) -> Result<Self, DateError> {
+        Self::from_bengali(self.bengali_date.add_months(months, overflow)?)
+    }
+
+    /// Add English months to the selected date
+    /// # Arguments
+    /// * `months` - i64, can be negative
+    /// * `overflow` - Overflow, the policy for the days that do not exist in the new English month
+    /// # Returns
+    /// * `Result<DualDate, DateError>` - The new date on both sides
+    /// # Example
+    /// ```
+    /// use ponjika::arithmetic::Overflow;
+    /// use ponjika::date::EnglishDate;
+    /// use ponjika::dual::DualDate;
+    /// use ponjika::months::EnglishMonths;
+    /// let date = EnglishDate::create_date(14, EnglishMonths::April, 2024).unwrap();
+    /// let date = DualDate::from_english(date).unwrap().add_english_months(1, Overflow::Clamp).unwrap();
+    /// assert_eq!(date.get_bengali_date().get_date(), (31, 1, 1431));
+    /// ```
+    /// # Note
+    /// * The Bengali date keeps the calendar variant of the selected date
+    /// * The function will return `DateError::DayOutOfMonth` if the day does not exist and the policy is `Overflow::Reject`
+    /// * The function will return `DateError::OutOfRange` if the year of the new date is out of the supported range
+    pub fn add_english_months(&self, months: i64, overflow: Overflow) -> Result<Self, DateError> {
+        Self::from_english_with_variant(
+            self.english_date.add_months(months, overflow)?,
+            self.get_variant(),
+        )
+    }
+}
+
+impl TryFrom<Date> for DualDate {
+    type Error = DateError;
+
+    /// Create a dual date from either side of a `Date`
+    /// # Example
+    /// ```
+    /// use ponjika::date::{BengaliDate, Date};
+    /// use ponjika::dual::DualDate;
+    /// use ponjika::months::BengaliMonths;
+    /// let date = Date::Bengali(BengaliDate::create_date(1, BengaliMonths::Baishakh, 1431).unwrap());
+    /// assert!(DualDate::try_from(date).is_ok());
+    /// assert!(DualDate::try_from(Date::Unknown).is_err());
+    /// ```
+    /// # Note
+    /// * A `Date::Julian` is paired with the Gregorian date of the same day
+    /// * The function will return `DateError::UnknownDate` if the date is `Date::Unknown`
+    fn try_from(date: Date) -> Result<Self, Self::Error> {
+        match date {
+            Date::Bengali(date) => Self::from_bengali(date),
+            Date::English(date) => Self::from_english(date),
+            Date::Julian(date) => {
+                Self::from_english(EnglishDate::from_rata_die(date.to_rata_die())?)
+            }
+            Date::Unknown => Err(DateError::UnknownDate),
+        }
+    }
+}
+
+impl Ord for DualDate {
+    /// Compare the dates chronologically
+    /// # Note
+    /// * Dates of different variants on the same day are ordered by the variant, like `BengaliDate`
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.bengali_date.cmp(&other.bengali_date)
+    }
+}
+
+impl PartialOrd for DualDate {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Add<Days> for DualDate {
+    type Output = Result<DualDate, DateError>;
+
+    /// Add a duration to the date
+    /// # Example
+    /// ```
+    /// use ponjika::arithmetic::Days;
+    /// use ponjika::date::BengaliDate;
+    /// use ponjika::dual::DualDate;
+    /// use ponjika::months::BengaliMonths;
+    /// let date = BengaliDate::create_date(1, BengaliMonths::Baishakh, 1431).unwrap();
+    /// let date = (DualDate::from_bengali(date).unwrap() + Days::new(7)).unwrap();
+    /// assert_eq!(date.get_english_date().get_date(), (21, 4, 2024));
+    /// ```
+    fn add(self, days: Days) -> Self::Output {
+        self.add_days(days.get_days())
+    }
+}
+
+impl Sub<Days> for DualDate {
+    type Output = Result<DualDate, DateError>;
+
+    /// Subtract a duration from the date
+    fn sub(self, days: Days) -> Self::Output {
+        self.sub_days(days.get_days())
+    }
+}
+
+impl fmt::Display for DualDate {
+    /// Display the Bengali date followed by the English date
+    /// # Returns
+    /// * `fmt::Result` - The date in both calendars
+    /// # Example
+    /// ```
+    /// use ponjika::date::BengaliDate;
+    /// use ponjika::dual::DualDate;
+    /// use ponjika::months::BengaliMonths;
+    /// let date = BengaliDate::create_date(1, BengaliMonths::Baishakh, 1431).unwrap();
+    /// let date = DualDate::from_bengali(date).unwrap();
+    /// assert_eq!(date.to_string(), "রবিবার, ১ বৈশাখ ১৪৩১ (14 April 2024)");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (day, _, year) = self.english_date.get_date();
+        let month = match self.english_date.get_month() {
+            Ok(month) => month,
+            Err(_) => return Err(fmt::Error),
+        };
+        write!(f, "{} ({} {} {})", self.bengali_date, day, month, year)
+    }
+}
@@ -0,0 +1,261 @@
+//! # Format: Writing dates with a pattern
+//! The `format` module writes the dates with strftime-style patterns.
+//! A specifier starts with `%`, can have flags, and ends with a letter:
+//!
+//! | Specifier | Meaning | Example |
+//! |-----------|---------|---------|
+//! | `%d` | The day of the month, two digits | ০১ |
+//! | `%m` | The month number, two digits | ০১ |
+//! | `%Y` | The year | ১৪৩১ |
+//! | `%y` | The last two digits of the year | ৩১ |
+//! | `%B` | The month name | বৈশাখ |
+//! | `%b` | The short month name, the full name for a Bengali month | বৈশাখ |
+//! | `%A` | The week day name | রবিবার |
+//! | `%a` | The short week day name | রবি |
+//! | `%E` | The era suffix | বঙ্গাব্দ |
+//! | `%S` | The Bengali season | গ্রীষ্ম |
+//! | `%F` | The ISO-like date, the same as `%Y-%m-%d` | ১৪৩১-০১-০১ |
+//! | `%%` | A literal `%` | % |
+//!
+//! The flags go between the `%` and the letter:
+//! * `-` drops the leading zero of `%d`, `%m` and `%y`
+//! * `O` writes the number in Bengali digits, and `%B`, `%A`, `%a` and `%E` of an English date in Bengali
+//! * `L` writes the number in Latin digits
+//! * `W` writes `%d` in the traditional words (পঁচিশে) and `%m`, `%Y` and `%y` in Bengali words
+//!
+//! Without `O` or `L`, a Bengali date is written in Bengali digits and an English date in Latin digits.
+
+use crate::date::{BengaliDate, Date, DateError, EnglishDate, JulianDate};
+use crate::days::{WeekDayError, WeekDays};
+use crate::months::{Month, MonthError};
+use crate::numerals::BengaliNumeral;
+use crate::parts::DateParts;
+use crate::words;
+
+/// The era suffix of the Bengali calendar
+pub(crate) const BENGALI_ERA: &str = "বঙ্গাব্দ";
+
+/// The era suffix of the English calendars
+const ENGLISH_ERA: &str = "AD";
+
+/// The era suffix of the English calendars in Bengali
+const ENGLISH_ERA_IN_BENGALI: &str = "খ্রিস্টাব্দ";
+
+/// The flags of a specifier
+#[derive(Clone, Copy)]
+struct Flags {
+    /// `false` if the `-` flag was given
+    pad: bool,
+    /// `true` for Bengali digits and names
+    bengali: bool,
+    /// `true` if the `W` flag was given
+    words: bool,
+}
+
+/// Write a number with the flags of its specifier
+/// # Arguments
+/// * `number` - u16
+/// * `width` - usize, the number of digits to pad to
+/// * `flags` - Flags
+/// # Returns
+/// * `String` - The number
+fn write_number(number: u16, width: usize, flags: Flags) -> String {
+    let width = match flags.pad {
+        true => width,
+        false => 0,
+    };
+
+    match (flags.words, flags.bengali) {
+        (true, _) => words::number_to_words(number as u64),
+        (false, true) => number.to_bengali_padded(width),
+        (false, false) => format!("{:0width$}", number, width = width),
+    }
+}
+
+impl DateParts {
+    /// Write the date with a pattern
+    /// # Arguments
+    /// * `pattern` - &str, see the `format` module for the specifiers
+    /// # Returns
+    /// * `Result<String, DateError>` - The date written with the pattern
+    /// # Example
+    /// ```
+    /// use ponjika::date::BengaliDate;
+    /// use ponjika::months::BengaliMonths;
+    /// let parts = BengaliDate::create_date(1, BengaliMonths::Baishakh, 1431).unwrap().parts();
+    /// assert_eq!(parts.format("%-d/%-m/%Y").unwrap(), "১/১/১৪৩১");
+    /// ```
+    /// # Note
+    /// * The function will return `DateError::WrongPattern` with the position of the `%` if the specifier is unknown
+    pub fn format(&self, pattern: &str) -> Result<String, DateError> {
+        let native_bengali = matches!(self.month_name, Month::Bengali(_));
+        let mut text = String::new();
+        let mut chars = pattern.chars().enumerate();
+
+        while let Some((position, c)) = chars.next() {
+            if c != '%' {
+                text.push(c);
+                continue;
+            }
+
+            let mut flags = Flags {
+                pad: true,
+                bengali: native_bengali,
+                words: false,
+            };
+            let specifier = loop {
+                match chars.next() {
+                    Some((_, '-')) => flags.pad = false,
+                    Some((_, 'O')) => flags.bengali = true,
+                    Some((_, 'L')) => flags.bengali = false,
+                    Some((_, 'W')) => flags.words = true,
+                    Some((_, specifier)) => break specifier,
+                    None => return Err(DateError::WrongPattern { position }),
+                }
+            };
+
+            match specifier {
+                'd' => match flags.words {
+                    true => text.push_str(words::day_to_words(self.day)?),
+                    false => text.push_str(&write_number(self.day as u16, 2, flags)),
+                },
+                'm' => text.push_str(&write_number(self.month as u16, 2, flags)),
+                'Y' => text.push_str(&write_number(self.year, 1, flags)),
+                'y' => text.push_str(&write_number(self.year % 100, 2, flags)),
+                'F' => {
+                    let flags = Flags {
+                        words: false,
+                        ..flags
+                    };
+                    text.push_str(&write_number(self.year, 1, flags));
+                    text.push('-');
+                    text.push_str(&write_number(self.month as u16, 2, flags));
+                    text.push('-');
+                    text.push_str(&write_number(self.day as u16, 2, flags));
+                }
+                'B' => match (self.month_name, flags.bengali) {
+                    (Month::English(month), true) => text.push_str(month.get_bengali_name()),
+                    _ => match self.month_name.get_month_name() {
+                        Ok(month) => text.push_str(&month),
+                        Err(err) => return Err(DateError::WrongMonth(err)),
+                    },
+                },
+                // the Bengali months have no abbreviations in common print, so they keep their names
+                'b' => match self.month_name {
+                    Month::English(month) => text.push_str(month.get_short_name()),
+                    Month::Bengali(month) => text.push_str(&month.to_string()),
+                    Month::Unknown => return Err(DateError::WrongMonth(MonthError::UnknownMonth)),
+                },
+                'A' => match (self.week_day, flags.bengali) {
+                    (WeekDays::English(week_day), true) => {
+                        text.push_str(&week_day.map_to_bengali().to_string())
+                    }
+                    _ => match self.week_day.get_week_day() {
+                        Ok(week_day) => text.push_str(&week_day),
+                        Err(err) => return Err(DateError::WrongWeekDay(err)),
+                    },
+                },
+                'a' => match self.week_day {
+                    WeekDays::English(week_day) => match flags.bengali {
+                        true => text.push_str(week_day.map_to_bengali().get_short_name()),
+                        false => text.push_str(week_day.get_short_name()),
+                    },
+                    WeekDays::Bengali(week_day) => text.push_str(week_day.get_short_name()),
+                    WeekDays::Unknown => {
+                        return Err(DateError::WrongWeekDay(WeekDayError::UnknownWeekDays))
+                    }
+                },
+                'E' => match (native_bengali, flags.bengali) {
+                    (true, _) => text.push_str(BENGALI_ERA),
+                    (false, true) => text.push_str(ENGLISH_ERA_IN_BENGALI),
+                    (false, false) => text.push_str(ENGLISH_ERA),
+                },
+                'S' => text.push_str(self.season.as_str()),
+                '%' => text.push('%'),
+                _ => return Err(DateError::WrongPattern { position }),
+            }
+        }
+
+        Ok(text)
+    }
+}
+
+impl BengaliDate {
+    /// Write the selected date with a pattern
+    /// # Arguments
+    /// * `pattern` - &str, see the `format` module for the specifiers
+    /// # Returns
+    /// * `Result<String, DateError>` - The date written with the pattern
+    /// # Example
+    /// ```
+    /// use ponjika::date::BengaliDate;
+    /// use ponjika::months::BengaliMonths;
+    /// let date = BengaliDate::create_date(1, BengaliMonths::Baishakh, 1431).unwrap();
+    /// assert_eq!(date.format("%Y %E").unwrap(), "১৪৩১ বঙ্গাব্দ");
+    /// assert_eq!(date.format("%B %-d, %Y").unwrap(), "বৈশাখ ১, ১৪৩১");
+    /// assert_eq!(date.format("%LF").unwrap(), "1431-01-01");
+    /// assert_eq!(date.format("%a, %-d %b (%S)").unwrap(), "রবি, ১ বৈশাখ (গ্রীষ্ম)");
+    /// ```
+    /// # Note
+    /// * The function will return `DateError::WrongPattern` with the position of the `%` if the specifier is unknown
+    pub fn format(&self, pattern: &str) -> Result<String, DateError> {
+        self.parts().format(pattern)
+    }
+}
+
+impl EnglishDate {
+    /// Write the selected date with a pattern
+    /// # Arguments
+    /// * `pattern` - &str, see the `format` module for the specifiers
+    /// # Returns
+    /// * `Result<String, DateError>` - The date written with the pattern
+    /// # Example
+    /// ```
+    /// use ponjika::date::EnglishDate;
+    /// use ponjika::months::EnglishMonths;
+    /// let date = EnglishDate::create_date(14, EnglishMonths::April, 2024).unwrap();
+    /// assert_eq!(date.format("%F").unwrap(), "2024-04-14");
+    /// assert_eq!(date.format("%a, %-d %b %Y").unwrap(), "Sun, 14 Apr 2024");
+    /// assert_eq!(date.format("%O-d/%O-m/%OY").unwrap(), "১৪/৪/২০২৪");
+    /// ```
+    /// # Note
+    /// * The function will return `DateError::WrongPattern` with the position of the `%` if the specifier is unknown
+    pub fn format(&self, pattern: &str) -> Result<String, DateError> {
+        self.parts().format(pattern)
+    }
+}
+
+impl JulianDate {
+    /// Write the selected date with a pattern
+    /// # Arguments
+    /// * `pattern` - &str, see the `format` module for the specifiers
+    /// # Returns
+    /// * `Result<String, DateError>` - The date written with the pattern
+    /// # Note
+    /// * The function will return `DateError::WrongPattern` with the position of the `%` if the specifier is unknown
+    pub fn format(&self, pattern: &str) -> Result<String, DateError> {
+        self.parts().format(pattern)
+    }
+}
+
+impl Date {
+    /// Write the selected date with a pattern
+    /// # Arguments
+    /// * `pattern` - &str, see the `format` module for the specifiers
+    /// # Returns
+    /// * `Result<String, DateError>` - The date written with the pattern
+    /// # Example
+    /// ```
+    /// use ponjika::date::{BengaliDate, Date};
+    /// use ponjika::months::BengaliMonths;
+    /// let date = Date::Bengali(BengaliDate::create_date(1, BengaliMonths::Baishakh, 1431).unwrap());
+    /// assert_eq!(date.format("%d.%m.%y").unwrap(), "০১.০১.৩১");
+    /// assert!(date.format("%Q").is_err());
+    /// ```
+    /// # Note
+    /// * The function will return `DateError::UnknownDate` if the date is `Date::Unknown`
+    /// * The function will return `DateError::WrongPattern` with the position of the `%` if the specifier is unknown
+    pub fn format(&self, pattern: &str) -> Result<String, DateError> {
+        self.parts()?.format(pattern)
+    }
+}
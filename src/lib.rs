@@ -1,16 +1,45 @@
+pub mod arithmetic;
 pub mod calendar;
+pub mod clock;
+pub mod convert;
 pub mod date;
 pub mod days;
+pub mod dual;
+pub mod format;
 pub mod months;
-mod season;
+pub mod names;
+pub mod numerals;
+mod ordinal;
+mod parse;
+pub mod parts;
+pub mod range;
+pub mod rata_die;
+pub mod season;
+mod siddhanta;
+mod sun;
+pub mod words;
 
+pub use arithmetic::*;
 pub use calendar::*;
+pub use clock::*;
+pub use convert::*;
 pub use date::*;
 pub use days::*;
+pub use dual::*;
 pub use months::*;
+pub use names::*;
+pub use numerals::*;
+pub use parts::*;
+pub use range::*;
+pub use rata_die::*;
+pub use season::*;
+pub use words::*;
 
+#[cfg(test)]
+#[allow(clippy::assertions_on_constants)]
 mod tests {
     use super::*;
+    use std::str::FromStr;
 
     #[test]
     fn test_conv_bengali_01() {
@@ -157,6 +186,165 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_conv_bengali_06() {
+        let english_date = EnglishDate::create_date(16, EnglishMonths::October, 2010);
+        match english_date {
+            Ok(e_date) => {
+                let bengali_date = calendar::get_bengali_date_from_gregorian(e_date);
+                match bengali_date {
+                    Ok(b_date) => {
+                        assert_eq!(
+                            (
+                                "৩১".to_string(),
+                                "শনিবার".to_string(),
+                                "আশ্বিন".to_string(),
+                                "১৪১৭".to_string()
+                            ),
+                            b_date.get_date().unwrap()
+                        );
+                    }
+                    Err(_) => {
+                        assert!(false);
+                    }
+                }
+            }
+            Err(_) => {
+                assert!(false);
+            }
+        }
+    }
+
+    #[test]
+    fn test_conv_bengali_variant_01() {
+        let english_date = EnglishDate::create_date(29, EnglishMonths::February, 2012);
+        match english_date {
+            Ok(e_date) => {
+                let bengali_date = calendar::get_bengali_date_from_gregorian_with_variant(
+                    e_date,
+                    CalendarVariant::Revised1987,
+                );
+                match bengali_date {
+                    Ok(b_date) => {
+                        assert_eq!(
+                            (
+                                "১৭".to_string(),
+                                "বুধবার".to_string(),
+                                "ফাল্গুন".to_string(),
+                                "১৪১৮".to_string()
+                            ),
+                            b_date.get_date().unwrap()
+                        );
+                    }
+                    Err(_) => {
+                        assert!(false);
+                    }
+                }
+            }
+            Err(_) => {
+                assert!(false);
+            }
+        }
+    }
+
+    #[test]
+    fn test_conv_english_variant_01() {
+        let bengali_date = BengaliDate::create_date_with_variant(
+            31,
+            BengaliMonths::Falgun,
+            1418,
+            CalendarVariant::Revised1987,
+        );
+        match bengali_date {
+            Ok(b_date) => {
+                let english_date = calendar::get_gregorian_date_from_bengali(b_date);
+                match english_date {
+                    Ok(e_date) => {
+                        assert_eq!(
+                            (
+                                "14".to_string(),
+                                "Wednesday".to_string(),
+                                "March".to_string(),
+                                "2012".to_string()
+                            ),
+                            e_date.get_date().unwrap()
+                        );
+                    }
+                    Err(_) => {
+                        assert!(false);
+                    }
+                }
+            }
+            Err(_) => {
+                assert!(false);
+            }
+        }
+    }
+
+    #[test]
+    fn test_conv_bengali_traditional_01() {
+        let english_date = EnglishDate::create_date(14, EnglishMonths::April, 2024);
+        match english_date {
+            Ok(e_date) => {
+                let bengali_date = calendar::get_bengali_date_from_gregorian_with_variant(
+                    e_date,
+                    CalendarVariant::Traditional,
+                );
+                match bengali_date {
+                    Ok(b_date) => {
+                        assert_eq!(
+                            (
+                                "১".to_string(),
+                                "রবিবার".to_string(),
+                                "বৈশাখ".to_string(),
+                                "১৪৩১".to_string()
+                            ),
+                            b_date.get_date().unwrap()
+                        );
+                    }
+                    Err(_) => {
+                        assert!(false);
+                    }
+                }
+            }
+            Err(_) => {
+                assert!(false);
+            }
+        }
+    }
+
+    #[test]
+    fn test_conv_bengali_traditional_02() {
+        let english_date = EnglishDate::create_date(14, EnglishMonths::April, 2023);
+        match english_date {
+            Ok(e_date) => {
+                let bengali_date = calendar::get_bengali_date_from_gregorian_with_variant(
+                    e_date,
+                    CalendarVariant::Traditional,
+                );
+                match bengali_date {
+                    Ok(b_date) => {
+                        assert_eq!(
+                            (
+                                "৩০".to_string(),
+                                "শুক্রবার".to_string(),
+                                "চৈত্র".to_string(),
+                                "১৪২৯".to_string()
+                            ),
+                            b_date.get_date().unwrap()
+                        );
+                    }
+                    Err(_) => {
+                        assert!(false);
+                    }
+                }
+            }
+            Err(_) => {
+                assert!(false);
+            }
+        }
+    }
+
     #[test]
     fn test_conv_english_01() {
         let bengali_date = BengaliDate::create_date(4, BengaliMonths::Ashwin, 1409);
@@ -186,6 +374,40 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_conv_english_traditional_01() {
+        let bengali_date = BengaliDate::create_date_with_variant(
+            32,
+            BengaliMonths::Shrawan,
+            1431,
+            CalendarVariant::Traditional,
+        );
+        match bengali_date {
+            Ok(b_date) => {
+                let english_date = calendar::get_gregorian_date_from_bengali(b_date);
+                match english_date {
+                    Ok(e_date) => {
+                        assert_eq!(
+                            (
+                                "17".to_string(),
+                                "Saturday".to_string(),
+                                "August".to_string(),
+                                "2024".to_string()
+                            ),
+                            e_date.get_date().unwrap()
+                        );
+                    }
+                    Err(_) => {
+                        assert!(false);
+                    }
+                }
+            }
+            Err(_) => {
+                assert!(false);
+            }
+        }
+    }
+
     #[test]
     fn test_conv_english_02() {
         let bengali_date = BengaliDate::create_date(31, BengaliMonths::Baishakh, 1419);
@@ -243,4 +465,1545 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_rata_die_01() {
+        let english_date = EnglishDate::create_date(14, EnglishMonths::April, 2024);
+        let bengali_date = BengaliDate::create_date(1, BengaliMonths::Baishakh, 1431);
+        match (english_date, bengali_date) {
+            (Ok(e_date), Ok(b_date)) => {
+                assert_eq!(e_date.to_rata_die(), b_date.to_rata_die());
+                assert_eq!(
+                    e_date.to_rata_die().get_bengali_week_day(),
+                    BengaliWeekDays::Robibar
+                );
+                match BengaliDate::from_rata_die(e_date.to_rata_die(), CalendarVariant::Revised2019)
+                {
+                    Ok(date) => assert_eq!(date.get_date(), (1, 1, 1431)),
+                    Err(_) => {
+                        assert!(false);
+                    }
+                }
+            }
+            _ => {
+                assert!(false);
+            }
+        }
+    }
+
+    #[test]
+    fn test_rata_die_02() {
+        for day in [i64::MIN, -1, 0, 1, i64::MAX] {
+            match EnglishDate::from_rata_die(RataDie::new(day)) {
+                Err(DateError::OutOfRange {
+                    field: DateField::DayNumber,
+                    value,
+                    ..
+                }) => assert_eq!(value, day),
+                _ => {
+                    assert!(false);
+                }
+            }
+            match BengaliDate::from_rata_die(RataDie::new(day), CalendarVariant::Traditional) {
+                Err(DateError::OutOfRange {
+                    field: DateField::DayNumber,
+                    value,
+                    ..
+                }) => assert_eq!(value, day),
+                _ => {
+                    assert!(false);
+                }
+            }
+            match JulianDate::from_rata_die(RataDie::new(day)) {
+                Err(DateError::OutOfRange {
+                    field: DateField::DayNumber,
+                    value,
+                    ..
+                }) => assert_eq!(value, day),
+                _ => {
+                    assert!(false);
+                }
+            }
+            assert!(Switchover::default()
+                .from_rata_die(RataDie::new(day))
+                .is_err());
+        }
+
+        let last = EnglishDate::create_date(31, EnglishMonths::December, 9999).unwrap();
+        let day = last.to_rata_die().get_day_number();
+        assert!(EnglishDate::from_rata_die(RataDie::new(day)).is_ok());
+        assert!(EnglishDate::from_rata_die(RataDie::new(day + 1)).is_err());
+        for variant in [CalendarVariant::Revised2019, CalendarVariant::Historical] {
+            let last =
+                BengaliDate::create_date_with_variant(30, BengaliMonths::Chaitra, 9406, variant)
+                    .unwrap()
+                    .to_rata_die()
+                    .get_day_number();
+            assert!(BengaliDate::from_rata_die(RataDie::new(last), variant).is_ok());
+            assert!(BengaliDate::from_rata_die(RataDie::new(last + 1), variant).is_err());
+        }
+    }
+
+    #[test]
+    fn test_leap_year_01() {
+        assert!(EnglishDate::create_date(29, EnglishMonths::February, 1900).is_err());
+        assert!(EnglishDate::create_date(29, EnglishMonths::February, 2100).is_err());
+        assert!(EnglishDate::create_date(29, EnglishMonths::February, 2000).is_ok());
+        assert!(EnglishDate::create_date(29, EnglishMonths::February, 2024).is_ok());
+
+        let english_date = EnglishDate::create_date(1, EnglishMonths::March, 2100);
+        match english_date {
+            Ok(e_date) => match calendar::get_bengali_date_from_gregorian(e_date) {
+                Ok(b_date) => {
+                    assert_eq!(
+                        b_date.get_bengali_date().unwrap().get_date(),
+                        (16, 11, 1506)
+                    );
+                }
+                Err(_) => {
+                    assert!(false);
+                }
+            },
+            Err(_) => {
+                assert!(false);
+            }
+        }
+    }
+
+    #[test]
+    fn test_conv_exhaustive_01() {
+        use chrono::Datelike;
+
+        // every day from 1 Baishakh 1 to the end of the supported Gregorian range
+        let first = chrono::NaiveDate::from_ymd_opt(594, 4, 14).unwrap();
+        let last = chrono::NaiveDate::from_ymd_opt(9999, 12, 31).unwrap();
+
+        for variant in [CalendarVariant::Revised1987, CalendarVariant::Revised2019] {
+            let mut previous: Option<(u8, u8, u16)> = None;
+            for day in first.iter_days().take_while(|day| *day <= last) {
+                let expected = (day.day() as u8, day.month() as u8, day.year() as u16);
+                let e_date = EnglishDate::create_date(
+                    expected.0,
+                    EnglishMonths::get_month(expected.1).unwrap(),
+                    expected.2,
+                )
+                .unwrap();
+                assert_eq!(
+                    e_date.to_rata_die().get_day_number(),
+                    day.num_days_from_ce() as i64
+                );
+
+                let b_date =
+                    calendar::get_bengali_date_from_gregorian_with_variant(e_date, variant)
+                        .unwrap()
+                        .get_bengali_date()
+                        .unwrap();
+                let (b_day, b_month, b_year) = b_date.get_date();
+                if let Some((p_day, p_month, p_year)) = previous {
+                    assert!(
+                        (b_day, b_month, b_year) == (p_day + 1, p_month, p_year)
+                            || (b_day, b_month, b_year) == (1, p_month + 1, p_year)
+                            || (b_day, b_month, b_year) == (1, 1, p_year + 1)
+                    );
+                }
+                previous = Some((b_day, b_month, b_year));
+
+                let e_date = calendar::get_gregorian_date_from_bengali(b_date)
+                    .unwrap()
+                    .get_english_date()
+                    .unwrap();
+                assert_eq!(e_date.get_date(), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_bengali_leap_year_01() {
+        assert!(BengaliDate::create_date(30, BengaliMonths::Falgun, 1430).is_ok());
+        match BengaliDate::create_date(31, BengaliMonths::Falgun, 1430) {
+            Ok(_) => {
+                assert!(false);
+            }
+            Err(err) => {
+                assert_eq!(err.to_string(), "DateError: Falgun 1430 has only 30 days");
+            }
+        }
+        match BengaliDate::create_date(30, BengaliMonths::Falgun, 1429) {
+            Ok(_) => {
+                assert!(false);
+            }
+            Err(err) => {
+                assert_eq!(err.to_string(), "DateError: Falgun 1429 has only 29 days");
+            }
+        }
+        assert!(BengaliDate::create_date_with_variant(
+            31,
+            BengaliMonths::Falgun,
+            1430,
+            CalendarVariant::Revised1987
+        )
+        .is_ok());
+        assert!(BengaliDate::create_date_with_variant(
+            31,
+            BengaliMonths::Falgun,
+            1429,
+            CalendarVariant::Revised1987
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_conv_bengali_historical_01() {
+        let english_date = EnglishDate::create_date(15, EnglishMonths::January, 1998);
+        match english_date {
+            Ok(e_date) => {
+                let bengali_date = calendar::get_bengali_date_from_gregorian_with_variant(
+                    e_date,
+                    CalendarVariant::Historical,
+                );
+                match bengali_date {
+                    Ok(b_date) => {
+                        assert_eq!(
+                            (
+                                "২".to_string(),
+                                "বৃহস্পতিবার".to_string(),
+                                "মাঘ".to_string(),
+                                "১৪০৪".to_string()
+                            ),
+                            b_date.get_date().unwrap()
+                        );
+                    }
+                    Err(_) => {
+                        assert!(false);
+                    }
+                }
+            }
+            Err(_) => {
+                assert!(false);
+            }
+        }
+    }
+
+    #[test]
+    fn test_conv_bengali_historical_02() {
+        let english_date = EnglishDate::create_date(13, EnglishMonths::April, 1968);
+        match english_date {
+            Ok(e_date) => {
+                let bengali_date = calendar::get_bengali_date_from_gregorian_with_variant(
+                    e_date,
+                    CalendarVariant::Historical,
+                );
+                match bengali_date {
+                    Ok(b_date) => {
+                        assert_eq!(
+                            (
+                                "১".to_string(),
+                                "শনিবার".to_string(),
+                                "বৈশাখ".to_string(),
+                                "১৩৭৫".to_string()
+                            ),
+                            b_date.get_date().unwrap()
+                        );
+                    }
+                    Err(_) => {
+                        assert!(false);
+                    }
+                }
+            }
+            Err(_) => {
+                assert!(false);
+            }
+        }
+    }
+
+    #[test]
+    fn test_conv_english_historical_01() {
+        let bengali_date = BengaliDate::create_date_with_variant(
+            25,
+            BengaliMonths::Ashwin,
+            1350,
+            CalendarVariant::Historical,
+        );
+        let traditional_date = BengaliDate::create_date_with_variant(
+            25,
+            BengaliMonths::Ashwin,
+            1350,
+            CalendarVariant::Traditional,
+        );
+        match (bengali_date, traditional_date) {
+            (Ok(b_date), Ok(t_date)) => {
+                assert_eq!(b_date.to_rata_die(), t_date.to_rata_die());
+                let english_date = calendar::get_gregorian_date_from_bengali(b_date);
+                match english_date {
+                    Ok(e_date) => {
+                        let rata_die = e_date.get_english_date().unwrap().to_rata_die();
+                        match BengaliDate::from_rata_die(rata_die, CalendarVariant::Historical) {
+                            Ok(date) => assert_eq!(date.get_date(), (25, 6, 1350)),
+                            Err(_) => {
+                                assert!(false);
+                            }
+                        }
+                    }
+                    Err(_) => {
+                        assert!(false);
+                    }
+                }
+            }
+            _ => {
+                assert!(false);
+            }
+        }
+    }
+
+    #[test]
+    fn test_julian_01() {
+        // Isaac Newton was born on 25th December 1642 (Julian), 4th January 1643 (Gregorian)
+        let julian_date = JulianDate::create_date(25, EnglishMonths::December, 1642);
+        let english_date = EnglishDate::create_date(4, EnglishMonths::January, 1643);
+        match (julian_date, english_date) {
+            (Ok(j_date), Ok(e_date)) => {
+                assert_eq!(j_date.to_rata_die(), e_date.to_rata_die());
+                assert_eq!(j_date.get_week_day().unwrap(), "Sunday");
+            }
+            _ => {
+                assert!(false);
+            }
+        }
+
+        for day_number in 217_000..3_650_000 {
+            let rata_die = RataDie::new(day_number);
+            match JulianDate::from_rata_die(rata_die) {
+                Ok(date) => assert_eq!(date.to_rata_die(), rata_die),
+                Err(_) => {
+                    assert!(false);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_conv_bengali_julian_01() {
+        let julian_date = JulianDate::create_date(4, EnglishMonths::April, 1600);
+        match julian_date {
+            Ok(j_date) => {
+                let bengali_date = calendar::get_bengali_date_from_julian(j_date);
+                match bengali_date {
+                    Ok(b_date) => {
+                        let b_date = b_date.get_bengali_date().unwrap();
+                        assert_eq!(b_date.get_date(), (1, 1, 1007));
+                        let julian_date = calendar::get_julian_date_from_bengali(b_date);
+                        match julian_date {
+                            Ok(date) => assert_eq!(date.to_string(), "Friday, 4 April 1600"),
+                            Err(_) => {
+                                assert!(false);
+                            }
+                        }
+                    }
+                    Err(_) => {
+                        assert!(false);
+                    }
+                }
+            }
+            Err(_) => {
+                assert!(false);
+            }
+        }
+    }
+
+    #[test]
+    fn test_switchover_01() {
+        // Britain and Bengal went from Wednesday 2nd to Thursday 14th September 1752
+        let first_day = EnglishDate::create_date(14, EnglishMonths::September, 1752).unwrap();
+        let switchover = Switchover::new(first_day);
+
+        match switchover.create_date(2, EnglishMonths::September, 1752) {
+            Ok(Date::Julian(date)) => assert_eq!(date.get_week_day().unwrap(), "Wednesday"),
+            _ => {
+                assert!(false);
+            }
+        }
+        match switchover.create_date(14, EnglishMonths::September, 1752) {
+            Ok(Date::English(date)) => assert_eq!(date.get_week_day().unwrap(), "Thursday"),
+            _ => {
+                assert!(false);
+            }
+        }
+        assert!(switchover
+            .create_date(3, EnglishMonths::September, 1752)
+            .is_err());
+        // 1700 was a leap year only in the Julian calendar
+        assert!(switchover
+            .create_date(29, EnglishMonths::February, 1700)
+            .is_ok());
+        assert!(Switchover::default()
+            .create_date(29, EnglishMonths::February, 1700)
+            .is_err());
+
+        let bengali_date = BengaliDate::create_date(1, BengaliMonths::Baishakh, 1159).unwrap();
+        match calendar::get_english_date_from_bengali_with_switchover(bengali_date, switchover) {
+            Ok(date) => assert_eq!(date.to_string(), "Friday, 3 April 1752"),
+            Err(_) => {
+                assert!(false);
+            }
+        }
+        let bengali_date = BengaliDate::create_date(1, BengaliMonths::Baishakh, 1160).unwrap();
+        match calendar::get_english_date_from_bengali_with_switchover(bengali_date, switchover) {
+            Ok(date) => assert_eq!(date.to_string(), "Saturday, 14 April 1753"),
+            Err(_) => {
+                assert!(false);
+            }
+        }
+    }
+
+    #[test]
+    fn test_bengali_week_day_01() {
+        let bengali_date = BengaliDate::create_date(31, BengaliMonths::Ashwin, 1417);
+        match bengali_date {
+            Ok(date) => {
+                assert_eq!(date.get_week_day().unwrap(), "শনিবার");
+                assert_eq!(date.to_string(), "শনিবার, ৩১ আশ্বিন ১৪১৭");
+            }
+            Err(_) => {
+                assert!(false);
+            }
+        }
+
+        let bengali_date = BengaliDate::create_date_with_weekday(
+            31,
+            BengaliWeekDays::Shonibar,
+            BengaliMonths::Ashwin,
+            1417,
+        );
+        assert!(bengali_date.is_ok());
+
+        let bengali_date = BengaliDate::create_date_with_weekday(
+            31,
+            BengaliWeekDays::Robibar,
+            BengaliMonths::Ashwin,
+            1417,
+        );
+        match bengali_date {
+            Err(DateError::WeekDayMismatch { given, actual }) => {
+                assert_eq!(given, BengaliWeekDays::Robibar);
+                assert_eq!(actual, BengaliWeekDays::Shonibar);
+            }
+            _ => {
+                assert!(false);
+            }
+        }
+    }
+
+    #[test]
+    fn test_today_with_clock_01() {
+        // 00:15 on 14th April in Dhaka, 23:45 on 13th April in Kolkata
+        let moment = chrono::DateTime::parse_from_rfc3339("2024-04-13T18:15:00Z").unwrap();
+        let clock = FixedClock::new(moment.to_utc());
+
+        let dhaka = BengaliTimeZone::Dhaka.get_offset();
+        match calendar::get_today_bengali_date_with_clock(
+            &clock,
+            &dhaka,
+            CalendarVariant::default(),
+        ) {
+            Ok(date) => assert_eq!(date.get_bengali_date().unwrap().get_date(), (1, 1, 1431)),
+            Err(_) => {
+                assert!(false);
+            }
+        }
+
+        let kolkata = BengaliTimeZone::Kolkata.get_offset();
+        match calendar::get_today_bengali_date_with_clock(
+            &clock,
+            &kolkata,
+            CalendarVariant::Traditional,
+        ) {
+            Ok(date) => assert_eq!(date.get_bengali_date().unwrap().get_date(), (30, 12, 1430)),
+            Err(_) => {
+                assert!(false);
+            }
+        }
+    }
+
+    #[test]
+    fn test_sunrise_01() {
+        // the sun rose at 03:43 UTC in London on 21st June 2024
+        let day = EnglishDate::create_date(21, EnglishMonths::June, 2024).unwrap();
+        let day = day.to_rata_die().get_day_number();
+        match sun::sunrise(day, 51.5074, -0.1278) {
+            Some(sunrise) => {
+                let minutes = (sunrise - day as f64) * 24.0 * 60.0;
+                assert!((minutes - (3.0 * 60.0 + 43.0)).abs() < 2.0);
+            }
+            None => {
+                assert!(false);
+            }
+        }
+
+        // the polar night in Tromsø
+        let day = EnglishDate::create_date(21, EnglishMonths::December, 2024).unwrap();
+        assert!(sun::sunrise(day.to_rata_die().get_day_number(), 69.65, 18.96).is_none());
+    }
+
+    #[test]
+    fn test_conv_bengali_sunrise_01() {
+        // the sun rises at about 05:37 in Dhaka in mid-April
+        let before = chrono::DateTime::parse_from_rfc3339("2024-04-14T05:00:00+06:00").unwrap();
+        let after = chrono::DateTime::parse_from_rfc3339("2024-04-14T06:00:00+06:00").unwrap();
+        let (latitude, longitude) = (23.81, 90.41);
+
+        match calendar::get_bengali_date_from_datetime(
+            before,
+            latitude,
+            longitude,
+            DayBoundary::Sunrise,
+            CalendarVariant::default(),
+        ) {
+            Ok(date) => assert_eq!(date.get_bengali_date().unwrap().get_date(), (30, 12, 1430)),
+            Err(_) => {
+                assert!(false);
+            }
+        }
+        match calendar::get_bengali_date_from_datetime(
+            after,
+            latitude,
+            longitude,
+            DayBoundary::Sunrise,
+            CalendarVariant::default(),
+        ) {
+            Ok(date) => assert_eq!(date.get_bengali_date().unwrap().get_date(), (1, 1, 1431)),
+            Err(_) => {
+                assert!(false);
+            }
+        }
+        // the same moment written in UTC belongs to the same Bengali day
+        match calendar::get_bengali_date_from_datetime(
+            after.to_utc(),
+            latitude,
+            longitude,
+            DayBoundary::Sunrise,
+            CalendarVariant::default(),
+        ) {
+            Ok(date) => assert_eq!(date.get_bengali_date().unwrap().get_date(), (1, 1, 1431)),
+            Err(_) => {
+                assert!(false);
+            }
+        }
+        match calendar::get_bengali_date_from_datetime(
+            before,
+            latitude,
+            longitude,
+            DayBoundary::Midnight,
+            CalendarVariant::default(),
+        ) {
+            Ok(date) => assert_eq!(date.get_bengali_date().unwrap().get_date(), (1, 1, 1431)),
+            Err(_) => {
+                assert!(false);
+            }
+        }
+
+        assert!(matches!(
+            calendar::get_bengali_date_from_datetime(
+                before,
+                95.0,
+                longitude,
+                DayBoundary::Sunrise,
+                CalendarVariant::default(),
+            ),
+            Err(DateError::WrongLocation { .. })
+        ));
+    }
+
+    #[test]
+    fn test_arithmetic_01() {
+        // Shrawan 1431 has 32 days in the traditional calendar
+        let bengali_date = BengaliDate::create_date_with_variant(
+            32,
+            BengaliMonths::Shrawan,
+            1431,
+            CalendarVariant::Traditional,
+        );
+        match bengali_date {
+            Ok(date) => {
+                match date.add_months(1, Overflow::Clamp) {
+                    Ok(next) => {
+                        assert_eq!(next.get_date(), (31, 5, 1431));
+                        assert_eq!(next.get_variant(), CalendarVariant::Traditional);
+                    }
+                    Err(_) => {
+                        assert!(false);
+                    }
+                }
+                match date.add_months(1, Overflow::Reject) {
+                    Err(DateError::DayOutOfMonth { days, .. }) => assert_eq!(days, 31),
+                    _ => {
+                        assert!(false);
+                    }
+                }
+                match date.add_days(365) {
+                    Ok(next) => match next.sub_days(365) {
+                        Ok(back) => assert_eq!(back.get_date(), (32, 4, 1431)),
+                        Err(_) => {
+                            assert!(false);
+                        }
+                    },
+                    Err(_) => {
+                        assert!(false);
+                    }
+                }
+            }
+            Err(_) => {
+                assert!(false);
+            }
+        }
+
+        let english_date = EnglishDate::create_date(31, EnglishMonths::December, 9999).unwrap();
+        assert!(english_date.add_days(1).is_err());
+        assert!(english_date.add_days(i64::MAX).is_err());
+        assert!(english_date.sub_months(i64::MIN, Overflow::Clamp).is_err());
+        match english_date - Days::new(365) {
+            Ok(date) => assert_eq!(date.get_date(), (31, 12, 9998)),
+            Err(_) => {
+                assert!(false);
+            }
+        }
+
+        // the day numbers far outside of the supported days are rejected before they are converted
+        let english_date = EnglishDate::create_date(14, EnglishMonths::April, 2024).unwrap();
+        let bengali_date = BengaliDate::create_date(1, BengaliMonths::Baishakh, 1431).unwrap();
+        for days in [
+            i64::MIN + 1,
+            -1_000_000_000,
+            1_000_000_000,
+            i64::MAX - 1_000_000,
+        ] {
+            match english_date.add_days(days) {
+                Err(DateError::OutOfRange {
+                    field: DateField::DayNumber,
+                    ..
+                }) => {}
+                _ => {
+                    assert!(false);
+                }
+            }
+            match bengali_date.add_days(days) {
+                Err(DateError::OutOfRange {
+                    field: DateField::DayNumber,
+                    ..
+                }) => {}
+                _ => {
+                    assert!(false);
+                }
+            }
+            assert!((english_date + Days::new(days)).is_err());
+            assert!((english_date - Days::new(days)).is_err());
+            assert!((bengali_date + Days::new(days)).is_err());
+            assert!((bengali_date - Days::new(days)).is_err());
+        }
+        assert!(english_date.sub_days(i64::MIN).is_err());
+        assert!(bengali_date.sub_days(i64::MIN).is_err());
+    }
+
+    #[test]
+    fn test_date_span_01() {
+        // the breakdown added back to the start must give the end, for months of 29 to 32 days
+        let start = BengaliDate::create_date_with_variant(
+            31,
+            BengaliMonths::Ashad,
+            1429,
+            CalendarVariant::Traditional,
+        )
+        .unwrap();
+        for offset in -400..400 {
+            let end = start.add_days(offset).unwrap();
+            match start.until(&end) {
+                Ok(span) => {
+                    assert_eq!(span.get_total_days(), offset);
+                    assert!(span.get_days().abs() < 32);
+                    let months = span.get_years() * 12 + span.get_months();
+                    match start
+                        .add_months(months, Overflow::Clamp)
+                        .and_then(|date| date.add_days(span.get_days()))
+                    {
+                        Ok(date) => assert_eq!(date.get_date(), end.get_date()),
+                        Err(_) => {
+                            assert!(false);
+                        }
+                    }
+                }
+                Err(_) => {
+                    assert!(false);
+                }
+            }
+        }
+
+        // the other date is measured in the variant of the first one
+        let start = BengaliDate::create_date(1, BengaliMonths::Baishakh, 1431).unwrap();
+        let end = BengaliDate::create_date_with_variant(
+            1,
+            BengaliMonths::Baishakh,
+            1432,
+            CalendarVariant::Traditional,
+        )
+        .unwrap();
+        match start.until(&end) {
+            Ok(span) => {
+                assert_eq!(span.get_total_days(), 366);
+                assert_eq!(span.get_parts(), (1, 0, 1));
+            }
+            Err(_) => {
+                assert!(false);
+            }
+        }
+    }
+
+    #[test]
+    fn test_ordering_01() {
+        use std::collections::{BTreeMap, HashSet};
+
+        let english_date = EnglishDate::create_date(14, EnglishMonths::April, 2024).unwrap();
+        let bengali_date = BengaliDate::create_date(1, BengaliMonths::Baishakh, 1431).unwrap();
+        let julian_date = JulianDate::create_date(1, EnglishMonths::April, 2024).unwrap();
+        assert!(english_date == bengali_date);
+        assert!(bengali_date == english_date);
+        assert!(julian_date == english_date);
+        assert!(julian_date == bengali_date);
+
+        let tomorrow = bengali_date.add_days(1).unwrap();
+        assert!(english_date < tomorrow);
+        assert!(tomorrow > english_date);
+        assert!(julian_date < tomorrow);
+
+        // a copy stays usable after the date is moved into a collection
+        let mut holidays = BTreeMap::new();
+        holidays.insert(tomorrow, "second day");
+        holidays.insert(bengali_date, "new year");
+        assert_eq!(holidays.values().next(), Some(&"new year"));
+
+        let mut seen = HashSet::new();
+        assert!(seen.insert(bengali_date));
+        assert!(!seen.insert(bengali_date));
+
+        let date = Date::Bengali(bengali_date);
+        assert_eq!(date.get_bengali_date(), Some(bengali_date));
+        assert_eq!(date.get_bengali_date(), Some(bengali_date));
+        assert!(BengaliMonths::Baishakh < BengaliMonths::Chaitra);
+        assert!(EnglishMonths::January < EnglishMonths::December);
+        assert!(BengaliWeekDays::Robibar < BengaliWeekDays::Shonibar);
+    }
+
+    #[test]
+    fn test_date_range_01() {
+        // every day of every month of a traditional year, in order and without gaps
+        match BengaliDate::days_of_year_with_variant(1431, CalendarVariant::Traditional) {
+            Ok(year) => {
+                let mut expected = year.get_start().to_rata_die().get_day_number();
+                let mut months = 0;
+                for month in year.months() {
+                    months += 1;
+                    for date in month.iter_days() {
+                        assert_eq!(date.to_rata_die().get_day_number(), expected);
+                        assert_eq!(date.get_date().1, months);
+                        expected += 1;
+                    }
+                }
+                assert_eq!(months, 12);
+                assert_eq!(expected - 1, year.get_end().to_rata_die().get_day_number());
+
+                let mut days = 0;
+                for week in year.weeks(BengaliWeekDays::Robibar).unwrap() {
+                    assert!(week.get_total_days() <= 7);
+                    if week.get_total_days() < 7 {
+                        assert!(
+                            week.get_start() == year.get_start()
+                                || week.get_end() == year.get_end()
+                        );
+                    }
+                    // 1 Baishakh 1431 was a Sunday, so every week starts on a Sunday
+                    assert_eq!(week.get_start().get_week_day().unwrap(), "রবিবার");
+                    days += week.get_total_days();
+                }
+                assert_eq!(days, year.get_total_days());
+            }
+            Err(_) => {
+                assert!(false);
+            }
+        }
+
+        let january = EnglishDate::days_of_month(EnglishMonths::January, 2024).unwrap();
+        let february = EnglishDate::days_of_month(EnglishMonths::February, 2024).unwrap();
+        assert!(!january.overlaps(&february));
+        let first = january.get_start();
+        let last = february.get_end();
+        let both = DateRange::exclusive(first, last);
+        assert_eq!(both.get_total_days(), 59);
+        assert_eq!(both.intersection(&february).unwrap().get_total_days(), 28);
+        assert!(both.contains(&BengaliDate::create_date(1, BengaliMonths::Magh, 1430).unwrap()));
+        assert!(!both.contains(&last));
+        assert_eq!(both.into_iter().count(), 59);
+    }
+
+    #[test]
+    fn test_date_range_02() {
+        // the days run until the last supported day and then stop
+        let english_date = EnglishDate::create_date(30, EnglishMonths::December, 9999).unwrap();
+        let mut days = english_date.iter_days();
+        assert_eq!(days.next().unwrap().get_date(), (30, 12, 9999));
+        assert_eq!(days.next().unwrap().get_date(), (31, 12, 9999));
+        assert!(days.next().is_none());
+        assert_eq!(english_date.iter_days().count(), 2);
+
+        for variant in [CalendarVariant::Revised2019, CalendarVariant::Traditional] {
+            let bengali_date =
+                BengaliDate::create_date_with_variant(1, BengaliMonths::Chaitra, 9406, variant)
+                    .unwrap();
+            let last = bengali_date.iter_days().last().unwrap();
+            assert_eq!(last.get_date().1, 12);
+            assert_eq!(last.get_date().2, 9406);
+            assert_eq!(last.get_variant(), variant);
+        }
+    }
+
+    #[test]
+    fn test_days_in_01() {
+        // the month lengths agree with chrono
+        for year in 593..=9999 {
+            let mut days_in_year = 0;
+            for month in 1..=12 {
+                let first = chrono::NaiveDate::from_ymd_opt(year as i32, month as u32, 1).unwrap();
+                let next = match month {
+                    12 => chrono::NaiveDate::from_ymd_opt(year as i32 + 1, 1, 1).unwrap(),
+                    _ => chrono::NaiveDate::from_ymd_opt(year as i32, month as u32 + 1, 1).unwrap(),
+                };
+                let days = EnglishMonths::get_month(month).unwrap().days_in(year);
+                assert_eq!(days as i64, (next - first).num_days());
+                days_in_year += days as u16;
+            }
+            assert_eq!(EnglishDate::days_in_year(year), days_in_year);
+            assert_eq!(EnglishDate::is_leap_year(year), days_in_year == 366);
+        }
+
+        // the month lengths agree with the conversion of the last day and the day after it
+        let variants = [
+            CalendarVariant::Reformed1966,
+            CalendarVariant::Revised1987,
+            CalendarVariant::Revised2019,
+            CalendarVariant::Traditional,
+            CalendarVariant::Historical,
+        ];
+        for variant in variants {
+            for year in 1360..1440 {
+                let mut days_in_year = 0;
+                for month in 1..=12 {
+                    let month = BengaliMonths::get_month(month).unwrap();
+                    let days = month.days_in_with_variant(year, variant);
+                    let last = BengaliDate::create_date_with_variant(days, month, year, variant);
+                    match last {
+                        Ok(date) => match date.add_days(1) {
+                            Ok(next) => assert_eq!(next.get_date().0, 1),
+                            Err(_) => {
+                                assert!(false);
+                            }
+                        },
+                        Err(_) => {
+                            assert!(false);
+                        }
+                    }
+                    assert!(
+                        BengaliDate::create_date_with_variant(days + 1, month, year, variant)
+                            .is_err()
+                    );
+                    days_in_year += days as u16;
+                }
+                assert_eq!(
+                    BengaliDate::days_in_year_with_variant(year, variant),
+                    days_in_year
+                );
+                assert_eq!(
+                    BengaliDate::days_of_year_with_variant(year, variant)
+                        .unwrap()
+                        .get_total_days(),
+                    days_in_year as i64
+                );
+            }
+        }
+
+        assert_eq!(BengaliMonths::Falgun.days_in(1430), 30);
+        assert_eq!(EnglishMonths::February.days_in(2100), 28);
+    }
+
+    #[test]
+    fn test_ordinal_01() {
+        // the ordinals walk the years day by day
+        for year in [593, 1900, 2000, 2023, 2024, 9999] {
+            let mut previous = EnglishDate::from_ordinal(year, 1).unwrap();
+            assert_eq!(previous.get_date(), (1, 1, year));
+            for ordinal in 2..=EnglishDate::days_in_year(year) {
+                let date = EnglishDate::from_ordinal(year, ordinal).unwrap();
+                assert_eq!(date.ordinal(), ordinal);
+                assert_eq!(previous.add_days(1).unwrap(), date);
+                previous = date;
+            }
+            assert_eq!(previous.get_date(), (31, 12, year));
+            assert!(EnglishDate::from_ordinal(year, 0).is_err());
+            assert!(EnglishDate::from_ordinal(year, EnglishDate::days_in_year(year) + 1).is_err());
+        }
+
+        let variants = [
+            CalendarVariant::Revised1987,
+            CalendarVariant::Revised2019,
+            CalendarVariant::Traditional,
+        ];
+        for variant in variants {
+            for year in [1400, 1430, 1431, 1432] {
+                let days = BengaliDate::days_in_year_with_variant(year, variant);
+                let mut previous =
+                    BengaliDate::from_ordinal_with_variant(year, 1, variant).unwrap();
+                assert_eq!(previous.get_date(), (1, 1, year));
+                for ordinal in 2..=days {
+                    let date =
+                        BengaliDate::from_ordinal_with_variant(year, ordinal, variant).unwrap();
+                    assert_eq!(date.ordinal(), ordinal);
+                    assert_eq!(previous.add_days(1).unwrap(), date);
+                    previous = date;
+                }
+                assert_eq!(previous.get_date().1, 12);
+                assert!(BengaliDate::from_ordinal_with_variant(year, days + 1, variant).is_err());
+            }
+        }
+
+        // the weeks start on the selected day and the week 1 holds the first day of the year
+        for first_day in [BengaliWeekDays::Shonibar, BengaliWeekDays::Robibar] {
+            let mut week = 1;
+            for date in BengaliDate::days_of_year(1431).unwrap() {
+                if date.ordinal() > 1 && date.to_rata_die().get_bengali_week_day() == first_day {
+                    week += 1;
+                }
+                assert_eq!(date.week_of_year(first_day).unwrap(), week);
+            }
+        }
+        let date = EnglishDate::create_date(31, EnglishMonths::December, 2024).unwrap();
+        assert_eq!(date.week_of_year(BengaliWeekDays::Robibar).unwrap(), 53);
+        assert!(date.week_of_year(BengaliWeekDays::UnImplemented).is_err());
+    }
+
+    // the Indian national (Saka) calendar, plugged in from outside of the crate
+    #[derive(Debug, Clone, Copy, Default, PartialEq)]
+    struct Saka;
+
+    impl Saka {
+        fn new_year(year: u16) -> RataDie {
+            let gregorian_year = year + 78;
+            match EnglishDate::is_leap_year(gregorian_year) {
+                true => DateIn::<Gregorian>::new(21, 3, gregorian_year),
+                false => DateIn::<Gregorian>::new(22, 3, gregorian_year),
+            }
+            .unwrap()
+            .to_rata_die()
+        }
+    }
+
+    impl Calendar for Saka {
+        fn get_name(&self) -> &'static str {
+            "Saka"
+        }
+
+        fn get_year_range(&self) -> std::ops::RangeInclusive<u16> {
+            515..=9920
+        }
+
+        fn get_months_in_year(&self, _year: u16) -> u8 {
+            12
+        }
+
+        fn get_days_in_month(&self, month: u8, year: u16) -> u8 {
+            match month {
+                1 if EnglishDate::is_leap_year(year + 78) => 31,
+                1 => 30,
+                2..=6 => 31,
+                7..=12 => 30,
+                _ => 0,
+            }
+        }
+
+        fn get_month_name(&self, _month: u8) -> Result<String, MonthError> {
+            Err(MonthError::UnknownMonth)
+        }
+
+        fn to_rata_die(&self, day: u8, month: u8, year: u16) -> RataDie {
+            let elapsed: i64 = (1..month)
+                .map(|month| self.get_days_in_month(month, year) as i64)
+                .sum();
+            RataDie::new(Saka::new_year(year).get_day_number() + elapsed + day as i64 - 1)
+        }
+
+        fn date_from_rata_die(&self, rata_die: RataDie) -> Result<(u8, u8, u16), DateError> {
+            let (_, _, year) = Gregorian.date_from_rata_die(rata_die)?;
+            let mut year = year - 78;
+            if rata_die < Saka::new_year(year) {
+                year -= 1;
+            }
+
+            let mut day = rata_die.get_day_number() - Saka::new_year(year).get_day_number() + 1;
+            let mut month = 1;
+            while day > self.get_days_in_month(month, year) as i64 {
+                day -= self.get_days_in_month(month, year) as i64;
+                month += 1;
+            }
+            Ok((day as u8, month, year))
+        }
+    }
+
+    #[test]
+    fn test_convert_01() {
+        // the generic dates agree with the conversion functions
+        let mut date = EnglishDate::create_date(1, EnglishMonths::January, 1800).unwrap();
+        let last = EnglishDate::create_date(31, EnglishMonths::December, 2100).unwrap();
+        while date <= last {
+            let gregorian = DateIn::from(date);
+            match calendar::get_bengali_date_from_gregorian(date) {
+                Ok(Date::Bengali(b_date)) => {
+                    assert_eq!(gregorian.to::<Bengali>().unwrap(), DateIn::from(b_date));
+                    assert_eq!(BengaliDate::try_from(DateIn::from(b_date)).unwrap(), b_date);
+                }
+                _ => {
+                    assert!(false);
+                }
+            }
+            let julian = JulianDate::from_rata_die(date.to_rata_die()).unwrap();
+            assert_eq!(gregorian.to::<Julian>().unwrap(), DateIn::from(julian));
+            assert_eq!(
+                gregorian.to::<Julian>().unwrap().to::<Gregorian>().unwrap(),
+                gregorian
+            );
+            date = date.add_days(1).unwrap();
+        }
+
+        let calendar = Bengali::new(CalendarVariant::Traditional);
+        let date = DateIn::<Gregorian>::new(14, 4, 2024).unwrap();
+        assert_eq!(date.to_calendar(calendar).unwrap().get_date(), (1, 1, 1431));
+        assert!(DateIn::<Gregorian>::new(31, 4, 2024).is_err());
+        assert!(DateIn::<Bengali>::new(1, 13, 1431).is_err());
+        assert!(DateIn::<Bengali>::new(1, 1, 9407).is_err());
+
+        // a calendar from outside of the crate converts like the others
+        let date = DateIn::<Gregorian>::new(26, 1, 2024).unwrap();
+        assert_eq!(date.to::<Saka>().unwrap().get_date(), (6, 11, 1945));
+        let date = DateIn::<Gregorian>::new(21, 3, 2024).unwrap();
+        assert_eq!(date.to::<Saka>().unwrap().get_date(), (1, 1, 1946));
+        let date = DateIn::<Saka>::new(1, 1, 1946).unwrap();
+        assert_eq!(date.to::<Bengali>().unwrap().get_date(), (7, 12, 1430));
+        for day in 700_000..740_000 {
+            let date = DateIn::from_rata_die(RataDie::new(day), Saka).unwrap();
+            assert_eq!(date.to_rata_die().get_day_number(), day);
+        }
+    }
+
+    #[test]
+    fn test_date_error_01() {
+        match BengaliDate::create_date(33, BengaliMonths::Baishakh, 1431) {
+            Err(DateError::OutOfRange {
+                field: DateField::Day,
+                value,
+                min,
+                max,
+            }) => assert_eq!((value, min, max), (33, 1, 32)),
+            _ => {
+                assert!(false);
+            }
+        }
+        match BengaliDate::create_date(1, BengaliMonths::Baishakh, 9407) {
+            Err(DateError::OutOfRange {
+                field: DateField::Year,
+                value,
+                min,
+                max,
+            }) => assert_eq!((value, min, max), (9407, 1, 9406)),
+            _ => {
+                assert!(false);
+            }
+        }
+        match BengaliDate::create_date(31, BengaliMonths::Kartik, 1431) {
+            Err(DateError::DayOutOfMonth { day, days, .. }) => assert_eq!((day, days), (31, 30)),
+            _ => {
+                assert!(false);
+            }
+        }
+        match EnglishDate::create_date(29, EnglishMonths::February, 2023) {
+            Err(DateError::DayOutOfMonth {
+                day,
+                month,
+                year,
+                days,
+            }) => {
+                assert_eq!((day, year, days), (29, 2023, 28));
+                assert_eq!(month, Month::English(EnglishMonths::February));
+            }
+            _ => {
+                assert!(false);
+            }
+        }
+        match EnglishDate::from_ordinal(2023, 366) {
+            Err(err @ DateError::OutOfRange { .. }) => assert_eq!(
+                err.to_string(),
+                "DateError: The day of the year 366 is out of range, it should be between 1 and 365"
+            ),
+            _ => {
+                assert!(false);
+            }
+        }
+        match Switchover::default().create_date(10, EnglishMonths::October, 1582) {
+            Err(DateError::SkippedDay { day, month, year }) => {
+                assert_eq!((day, month, year), (10, EnglishMonths::October, 1582))
+            }
+            _ => {
+                assert!(false);
+            }
+        }
+
+        // the errors work with `?` into a boxed error and chain their causes
+        fn parse() -> Result<EnglishDate, Box<dyn std::error::Error>> {
+            Ok(EnglishDate::create_date(1, EnglishMonths::January, 2024)?.add_days(i64::MAX)?)
+        }
+        assert!(parse().is_err());
+        let err = DateError::WrongWeekDay(WeekDayError::UnknownWeekDays);
+        match std::error::Error::source(&err) {
+            Some(source) => assert_eq!(source.to_string(), "WeekDayError: Unknown week days"),
+            None => {
+                assert!(false);
+            }
+        }
+        assert!(std::error::Error::source(&DateError::NoSunrise).is_none());
+    }
+
+    #[test]
+    fn test_date_parts_01() {
+        // the parts display the same strings as the dates
+        for day in BengaliDate::days_of_year(1431).unwrap() {
+            let parts = day.parts();
+            assert_eq!(parts.to_string(), day.to_string());
+            assert_eq!((parts.day, parts.month, parts.year), day.get_date());
+            assert_eq!(
+                parts.week_day.get_week_day().unwrap(),
+                day.get_week_day().unwrap()
+            );
+            assert_eq!(
+                parts.month_name.get_month_name().unwrap(),
+                day.get_month().unwrap()
+            );
+
+            let english_date = calendar::get_gregorian_date_from_bengali(day)
+                .unwrap()
+                .get_english_date()
+                .unwrap();
+            let english_parts = english_date.parts();
+            assert_eq!(english_parts.to_string(), english_date.to_string());
+            assert_eq!(english_parts.season, parts.season);
+            match parts.week_day {
+                WeekDays::Bengali(week_day) => assert_eq!(
+                    english_parts.week_day,
+                    WeekDays::English(week_day.map_to_english())
+                ),
+                _ => {
+                    assert!(false);
+                }
+            }
+        }
+
+        let date = BengaliDate::create_date(15, BengaliMonths::Shrawan, 1431).unwrap();
+        match Date::Bengali(date).parts() {
+            Ok(DateParts {
+                month_name: Month::Bengali(BengaliMonths::Shrawan),
+                season,
+                ..
+            }) => assert_eq!(season.to_string(), "বর্ষা"),
+            _ => {
+                assert!(false);
+            }
+        }
+
+        // the season of an English date follows the variant of its Bengali date
+        let date = EnglishDate::create_date(14, EnglishMonths::April, 2025).unwrap();
+        for variant in [CalendarVariant::Revised2019, CalendarVariant::Traditional] {
+            let bengali = BengaliDate::from_rata_die(date.to_rata_die(), variant).unwrap();
+            assert_eq!(
+                date.parts_with_variant(variant).season,
+                bengali.parts().season
+            );
+        }
+    }
+
+    #[test]
+    fn test_dual_date_01() {
+        // both sides stay on the same day
+        let mut date = EnglishDate::create_date(1, EnglishMonths::January, 2020).unwrap();
+        let mut previous: Option<DualDate> = None;
+        for _ in 0..2000 {
+            let dual = DualDate::from_english(date).unwrap();
+            assert_eq!(dual.get_english_date(), date);
+            assert_eq!(dual.get_english_date(), dual.get_bengali_date());
+            assert_eq!(
+                DualDate::from_bengali(dual.get_bengali_date()).unwrap(),
+                dual
+            );
+            if let Some(previous) = previous {
+                assert!(previous < dual);
+                assert_eq!((previous + Days::new(1)).unwrap(), dual);
+                assert_eq!((dual - Days::new(1)).unwrap(), previous);
+            }
+            previous = Some(dual);
+            date = date.add_days(1).unwrap();
+        }
+
+        let date = BengaliDate::create_date(1, BengaliMonths::Baishakh, 1431).unwrap();
+        let dual = DualDate::try_from(Date::Bengali(date)).unwrap();
+        assert_eq!(dual.to_string(), "রবিবার, ১ বৈশাখ ১৪৩১ (14 April 2024)");
+        assert!(dual == date);
+        assert!(dual > EnglishDate::create_date(13, EnglishMonths::April, 2024).unwrap());
+
+        // the months are counted in the calendar that is asked for
+        let clamped = dual
+            .add_days(30)
+            .unwrap()
+            .add_bengali_months(1, Overflow::Clamp)
+            .unwrap();
+        assert_eq!(clamped.get_bengali_date().get_date(), (31, 2, 1431));
+        assert!(dual
+            .add_days(30)
+            .unwrap()
+            .add_bengali_months(6, Overflow::Reject)
+            .is_err());
+        let english = dual.add_english_months(1, Overflow::Reject).unwrap();
+        assert_eq!(english.get_english_date().get_date(), (14, 5, 2024));
+        assert_eq!(english.get_variant(), CalendarVariant::Revised2019);
+    }
+
+    #[test]
+    fn test_format_01() {
+        // the numbers read back the same as the date
+        let mut date = BengaliDate::create_date(1, BengaliMonths::Baishakh, 1430).unwrap();
+        for _ in 0..800 {
+            let (day, month, year) = date.get_date();
+            assert_eq!(
+                date.format("%L-d/%L-m/%LY").unwrap(),
+                format!("{}/{}/{}", day, month, year)
+            );
+            assert_eq!(
+                date.format("%-d %B %Y").unwrap(),
+                date.parts().to_string().split(", ").nth(1).unwrap()
+            );
+            date = date.add_days(1).unwrap();
+        }
+
+        let date = BengaliDate::create_date(1, BengaliMonths::Baishakh, 1431).unwrap();
+        assert_eq!(date.format("%Y %E").unwrap(), "১৪৩১ বঙ্গাব্দ");
+        assert_eq!(date.format("%-d/%-m/%Y").unwrap(), "১/১/১৪৩১");
+        assert_eq!(date.format("%B %-d, %Y").unwrap(), "বৈশাখ ১, ১৪৩১");
+        assert_eq!(date.format("%A (%a), %S").unwrap(), "রবিবার (রবি), গ্রীষ্ম");
+        for month in 1..=12 {
+            let month = BengaliMonths::get_month(month).unwrap();
+            let date = BengaliDate::create_date(1, month, 1431).unwrap();
+            assert_eq!(date.format("%b").unwrap(), date.format("%B").unwrap());
+        }
+        assert_eq!(
+            date.format("%F %LF 100%%").unwrap(),
+            "১৪৩১-০১-০১ 1431-01-01 100%"
+        );
+
+        let date = EnglishDate::create_date(5, EnglishMonths::March, 2024).unwrap();
+        assert_eq!(
+            date.format("%A, %d %B %Y %E").unwrap(),
+            "Tuesday, 05 March 2024 AD"
+        );
+        assert_eq!(date.format("%a %b %y %S").unwrap(), "Tue Mar 24 বসন্ত");
+
+        // the position is counted in characters
+        match date.format("%Y-%m-%d %ঝ") {
+            Err(DateError::WrongPattern { position }) => assert_eq!(position, 9),
+            _ => {
+                assert!(false);
+            }
+        }
+        match date.format("%Y %-") {
+            Err(DateError::WrongPattern { position }) => assert_eq!(position, 3),
+            _ => {
+                assert!(false);
+            }
+        }
+        assert!(Date::Unknown.format("%F").is_err());
+    }
+    #[test]
+    fn test_parse_01() {
+        // a formatted date reads back as the same date
+        let mut date = BengaliDate::create_date(1, BengaliMonths::Baishakh, 1430).unwrap();
+        for _ in 0..800 {
+            for pattern in ["%A, %-d %B %Y", "%d/%m/%Y", "%LF", "%a %-d %b %Y %E"] {
+                let text = date.format(pattern).unwrap();
+                assert_eq!(BengaliDate::parse(&text, pattern).unwrap(), date);
+            }
+            let text = date.parts().to_string();
+            assert_eq!(text.parse::<BengaliDate>().unwrap(), date);
+            date = date.add_days(1).unwrap();
+        }
+
+        for text in [
+            "২৫ আশ্বিন ১৪১৭",
+            "২৫/০৬/১৪১৭",
+            "রবিবার, ২৫ আশ্বিন ১৪১৭",
+            "রবি ২৫ আশ্বিন ১৪১৭ বঙ্গাব্দ",
+            "আশ্বিন ২৫, ১৪১৭",
+            "1417-06-25",
+            "২৫.6.১৪১৭",
+        ] {
+            match BengaliDate::from_str(text) {
+                Ok(date) => assert_eq!(date.get_date(), (25, 6, 1417)),
+                Err(_) => {
+                    assert!(false);
+                }
+            }
+        }
+
+        // the error points to the part of the input that is wrong
+        match BengaliDate::from_str("সোমবার, ২৫ আশ্বিন ১৪১৭") {
+            Err(DateError::ParseError {
+                position: 0,
+                cause: Some(cause),
+            }) => match *cause {
+                DateError::WeekDayMismatch { given, actual } => {
+                    assert_eq!(given, BengaliWeekDays::Sombar);
+                    assert_eq!(actual, BengaliWeekDays::Robibar);
+                }
+                _ => {
+                    assert!(false);
+                }
+            },
+            _ => {
+                assert!(false);
+            }
+        }
+        match BengaliDate::from_str("৩২ আশ্বিন ১৪১৭") {
+            Err(DateError::ParseError {
+                position: 0,
+                cause: Some(cause),
+            }) => assert!(matches!(*cause, DateError::DayOutOfMonth { .. })),
+            _ => {
+                assert!(false);
+            }
+        }
+        match BengaliDate::parse("২৫/১৩/১৪১৭", "%d/%m/%Y") {
+            Err(DateError::ParseError {
+                position: 3,
+                cause: Some(_),
+            }) => {}
+            _ => {
+                assert!(false);
+            }
+        }
+        match BengaliDate::from_str("২৫ আশিন ১৪১৭") {
+            Err(DateError::ParseError {
+                position: 3,
+                cause: None,
+            }) => {}
+            _ => {
+                assert!(false);
+            }
+        }
+        match BengaliDate::parse("২৫ আশ্বিন", "%d %B") {
+            Err(DateError::WrongPattern { position: 5 }) => {}
+            _ => {
+                assert!(false);
+            }
+        }
+    }
+
+    #[test]
+    fn test_resolve_names_01() {
+        // every name the crate writes resolves to itself
+        for month in 1..=12 {
+            let month = BengaliMonths::get_month(month).unwrap();
+            for name in [month.to_string(), format!("{:?}", month)] {
+                let found = BengaliMonths::resolve(&name).unwrap();
+                assert_eq!(found.value, month);
+                assert_eq!(found.confidence, 1.0);
+            }
+        }
+        for week_day in [
+            "Sunday",
+            "Monday",
+            "Tuesday",
+            "Wednesday",
+            "Thursday",
+            "Friday",
+            "Saturday",
+        ] {
+            let bengali = BengaliWeekDays::get_english_weekday(week_day).unwrap();
+            for name in [
+                bengali.to_string(),
+                bengali.get_short_name().to_string(),
+                format!("{:?}", bengali),
+                week_day.to_string(),
+            ] {
+                let found = BengaliWeekDays::resolve(&name).unwrap();
+                assert_eq!(found.value, bengali);
+                assert_eq!(found.confidence, 1.0);
+            }
+        }
+
+        for (name, month) in [
+            ("Boishakh", BengaliMonths::Baishakh),
+            ("baisakh", BengaliMonths::Baishakh),
+            ("Joishtho", BengaliMonths::Jestha),
+            ("JYOISHTHO", BengaliMonths::Jestha),
+            ("Agrahayan", BengaliMonths::Ogrohaeon),
+            ("Ogrohayon", BengaliMonths::Ogrohaeon),
+            ("আষা\u{09DD}", BengaliMonths::Ashad),
+            (" Phal-gun. ", BengaliMonths::Falgun),
+        ] {
+            let found = BengaliMonths::resolve(name).unwrap();
+            assert_eq!(found.value, month);
+            assert_eq!(found.confidence, 1.0);
+        }
+
+        // a misspelt name is found with a lower confidence
+        let found = BengaliMonths::resolve("Choitrow").unwrap();
+        assert_eq!(found.value, BengaliMonths::Chaitra);
+        assert!(found.confidence >= MIN_CONFIDENCE && found.confidence < 1.0);
+        let found = BengaliWeekDays::resolve("Brihospotibaar").unwrap();
+        assert_eq!(found.value, BengaliWeekDays::Brihoshpotibar);
+        assert!(found.confidence < 1.0);
+
+        assert_eq!(
+            BengaliMonths::resolve("xyz").unwrap_err(),
+            MonthError::UnknownMonth
+        );
+
+        // the English months are not taken for the Bengali months they look like
+        for name in ["March", "May", "Mar", "Apr"] {
+            assert_eq!(BengaliMonths::resolve(name), Err(MonthError::UnknownMonth));
+        }
+        for month in 1..=12 {
+            let month = EnglishMonths::get_month(month).unwrap();
+            assert!(BengaliMonths::resolve(&month.to_string()).is_err());
+            assert!(BengaliMonths::resolve(month.get_short_name()).is_err());
+        }
+        // a name as close to Ashad as to Ashwin is not guessed
+        assert_eq!(
+            BengaliMonths::resolve("Ashan"),
+            Err(MonthError::UnknownMonth)
+        );
+        // the short spellings only match exactly
+        assert_eq!(
+            BengaliMonths::resolve("mag").unwrap().value,
+            BengaliMonths::Magh
+        );
+        assert!(BengaliMonths::resolve("kay").is_err());
+        assert_eq!(
+            BengaliWeekDays::resolve("").unwrap_err(),
+            WeekDayError::UnknownWeekDays
+        );
+    }
+
+    #[test]
+    fn test_numerals_01() {
+        // the numbers read back from any mix of digits
+        for number in (-2000i32..2000).chain([i32::MIN, i32::MAX]) {
+            let bengali = number.to_bengali();
+            assert!(bengali.chars().all(|c| !c.is_ascii_digit()));
+            assert_eq!(to_latin_digits(&bengali), number.to_string());
+            assert_eq!(parse_number::<i32>(&bengali), Ok(number));
+            let mixed: String = bengali
+                .chars()
+                .zip(number.to_string().chars())
+                .enumerate()
+                .map(|(i, (bengali, latin))| if i % 2 == 0 { bengali } else { latin })
+                .collect();
+            assert_eq!(parse_number::<i32>(&mixed), Ok(number));
+        }
+        assert_eq!(
+            u128::MAX.to_bengali(),
+            to_bengali_digits(&u128::MAX.to_string())
+        );
+        assert_eq!(0usize.to_bengali_padded(4), "০০০০");
+        assert_eq!(7u8.to_bengali_padded(0), "৭");
+        assert_eq!(format_decimal(1431.5, 1), "১৪৩১.৫");
+        assert_eq!(parse_decimal("-১২.৫০"), Ok(-12.5));
+
+        // the date code writes the same digits
+        let date = BengaliDate::create_date(9, BengaliMonths::Poush, 1431).unwrap();
+        assert_eq!(date.get_day().unwrap(), 9u8.to_bengali());
+        assert_eq!(date.get_year().unwrap(), 1431u16.to_bengali());
+        assert_eq!(date.format("%d").unwrap(), 9u8.to_bengali_padded(2));
+
+        assert_eq!(parse_number::<u8>(""), Err(NumeralError::NoDigits));
+        assert_eq!(parse_number::<i8>("-"), Err(NumeralError::NoDigits));
+        assert_eq!(
+            parse_number::<u16>("১৪x১"),
+            Err(NumeralError::WrongCharacter {
+                position: 2,
+                character: 'x'
+            })
+        );
+        assert_eq!(parse_number::<u8>("-১"), Err(NumeralError::OutOfRange));
+        assert_eq!(parse_number::<u8>("২৫৬"), Err(NumeralError::OutOfRange));
+    }
+
+    #[test]
+    fn test_words_01() {
+        // every number below a hundred has its own word
+        let mut seen = std::collections::HashSet::new();
+        for number in 0..100 {
+            let word = number_to_words(number);
+            assert!(!word.contains(' '));
+            assert!(seen.insert(word));
+        }
+        for (number, words) in [
+            (100, "একশো"),
+            (105, "একশো পাঁচ"),
+            (1_000, "এক হাজার"),
+            (1_431, "এক হাজার চারশো একত্রিশ"),
+            (99_999, "নিরানব্বই হাজার নয়শো নিরানব্বই"),
+            (100_000, "এক লক্ষ"),
+            (1_234_567, "বারো লক্ষ চৌত্রিশ হাজার পাঁচশো সাতষট্টি"),
+            (10_000_000, "এক কোটি"),
+            (1_000_000_000, "একশো কোটি"),
+            (2_000_000_000_001, "দুই লক্ষ কোটি এক"),
+        ] {
+            assert_eq!(number_to_words(number), words);
+        }
+        assert!(number_to_words(u64::MAX).ends_with("পনেরো"));
+
+        for day in 1..=32 {
+            let word = day_to_words(day).unwrap();
+            assert!(["া", "ই", "ে"].iter().any(|end| word.ends_with(end)));
+        }
+        match day_to_words(0) {
+            Err(DateError::OutOfRange {
+                field: DateField::Day,
+                ..
+            }) => {}
+            _ => {
+                assert!(false);
+            }
+        }
+
+        let date = BengaliDate::create_date(25, BengaliMonths::Baishakh, 1431).unwrap();
+        assert_eq!(
+            date.to_long_words().unwrap(),
+            "পঁচিশে বৈশাখ, এক হাজার চারশো একত্রিশ বঙ্গাব্দ"
+        );
+        assert_eq!(
+            Date::Bengali(date).to_long_words().unwrap(),
+            date.to_long_words().unwrap()
+        );
+        assert_eq!(date.format("%A, %Wd %B").unwrap(), "বুধবার, পঁচিশে বৈশাখ");
+        assert_eq!(date.format("%Wm মাস").unwrap(), "এক মাস");
+
+        let date = EnglishDate::create_date(14, EnglishMonths::April, 2024).unwrap();
+        assert_eq!(
+            date.to_long_words().unwrap(),
+            "চোদ্দোই এপ্রিল, দুই হাজার চব্বিশ খ্রিস্টাব্দ"
+        );
+        assert_eq!(date.format("%OA, %Od %OB").unwrap(), "রবিবার, ১৪ এপ্রিল");
+        assert_eq!(date.format("%A, %d %B %E").unwrap(), "Sunday, 14 April AD");
+        assert!(Date::Unknown.to_long_words().is_err());
+    }
 }
@@ -6,9 +6,11 @@
 
 use std::fmt;
 
+use crate::calendar::{self, CalendarVariant};
+
 /// # `MonthError`: The error enum for the months.
 /// The enum variants are the error messages for the months.
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum MonthError {
     /// The UnknownMonth variant is used when the month is neither English nor Bengali.
     UnknownMonth,
@@ -34,12 +36,14 @@ impl std::fmt::Display for MonthError {
     }
 }
 
+impl std::error::Error for MonthError {}
+
 // Use the Result type to handle the error for the months
 type Result<T> = std::result::Result<T, MonthError>;
 
 /// # `Month`: The enum for the months.
 /// The enum variants are the English and Bengali months.
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Month {
     /// The English variant is used to represent the English months.
     English(EnglishMonths),
@@ -75,7 +79,7 @@ impl Month {
 
 /// # `EnglishMonths`: The enum for the English months.
 /// The enum variants are the English months.
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum EnglishMonths {
     January,
     February,
@@ -155,6 +159,83 @@ impl EnglishMonths {
             EnglishMonths::December => 12,
         }
     }
+
+    /// Get the number of days of the selected month in a year
+    /// # Arguments
+    /// * `year` - u16
+    /// # Returns
+    /// * `u8` - The number of days
+    /// # Example
+    /// ```
+    /// use ponjika::months::EnglishMonths;
+    /// assert_eq!(EnglishMonths::February.days_in(2024), 29);
+    /// assert_eq!(EnglishMonths::February.days_in(2100), 28);
+    /// assert_eq!(EnglishMonths::April.days_in(2100), 30);
+    /// ```
+    /// # Note
+    /// * The function will use the proleptic Gregorian calendar, where a century year is a leap year only if it is
+    ///   divisible by 400
+    pub fn days_in(&self, year: u16) -> u8 {
+        match self {
+            EnglishMonths::February if calendar::is_leap_year(year as i64) => 29,
+            EnglishMonths::February => 28,
+            EnglishMonths::April
+            | EnglishMonths::June
+            | EnglishMonths::September
+            | EnglishMonths::November => 30,
+            _ => 31,
+        }
+    }
+
+    /// Get the short name of the selected month
+    /// # Returns
+    /// * `&str` - The first three letters of the month name
+    /// # Example
+    /// ```
+    /// use ponjika::months::EnglishMonths;
+    /// assert_eq!(EnglishMonths::September.get_short_name(), "Sep");
+    /// ```
+    pub fn get_short_name(&self) -> &str {
+        match self {
+            EnglishMonths::January => "Jan",
+            EnglishMonths::February => "Feb",
+            EnglishMonths::March => "Mar",
+            EnglishMonths::April => "Apr",
+            EnglishMonths::May => "May",
+            EnglishMonths::June => "Jun",
+            EnglishMonths::July => "Jul",
+            EnglishMonths::August => "Aug",
+            EnglishMonths::September => "Sep",
+            EnglishMonths::October => "Oct",
+            EnglishMonths::November => "Nov",
+            EnglishMonths::December => "Dec",
+        }
+    }
+
+    /// Get the name of the selected month in Bengali script
+    /// # Returns
+    /// * `&str` - The month name as it is written in Bengali
+    /// # Example
+    /// ```
+    /// use ponjika::months::EnglishMonths;
+    /// assert_eq!(EnglishMonths::April.get_bengali_name(), "এপ্রিল");
+    /// ```
+    pub fn get_bengali_name(&self) -> &str {
+        match self {
+            EnglishMonths::January => "জানুয়ারি",
+            EnglishMonths::February => "ফেব্রুয়ারি",
+            EnglishMonths::March => "মার্চ",
+            EnglishMonths::April => "এপ্রিল",
+            EnglishMonths::May => "মে",
+            EnglishMonths::June => "জুন",
+            EnglishMonths::July => "জুলাই",
+            EnglishMonths::August => "আগস্ট",
+            EnglishMonths::September => "সেপ্টেম্বর",
+            EnglishMonths::October => "অক্টোবর",
+            EnglishMonths::November => "নভেম্বর",
+            EnglishMonths::December => "ডিসেম্বর",
+        }
+    }
 }
 
 impl fmt::Display for EnglishMonths {
@@ -187,7 +268,7 @@ impl fmt::Display for EnglishMonths {
 
 /// # `BengaliMonths`: The enum for the Bengali months.
 /// The enum variants are the Bengali months.
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum BengaliMonths {
     Baishakh,
     Jestha,
@@ -267,6 +348,42 @@ impl BengaliMonths {
             BengaliMonths::Chaitra => 12,
         }
     }
+
+    /// Get the number of days of the selected month in a year
+    /// # Arguments
+    /// * `year` - u16
+    /// # Returns
+    /// * `u8` - The number of days
+    /// # Example
+    /// ```
+    /// use ponjika::months::BengaliMonths;
+    /// assert_eq!(BengaliMonths::Falgun.days_in(1430), 30);
+    /// assert_eq!(BengaliMonths::Falgun.days_in(1431), 29);
+    /// ```
+    /// # Note
+    /// * The function will use the `CalendarVariant::Revised2019` rules
+    pub fn days_in(&self, year: u16) -> u8 {
+        self.days_in_with_variant(year, CalendarVariant::default())
+    }
+
+    /// Get the number of days of the selected month in a year of a calendar variant
+    /// # Arguments
+    /// * `year` - u16
+    /// * `variant` - CalendarVariant
+    /// # Returns
+    /// * `u8` - The number of days
+    /// # Example
+    /// ```
+    /// use ponjika::calendar::CalendarVariant;
+    /// use ponjika::months::BengaliMonths;
+    /// assert_eq!(BengaliMonths::Ashwin.days_in_with_variant(1420, CalendarVariant::Revised1987), 30);
+    /// assert_eq!(BengaliMonths::Shrawan.days_in_with_variant(1431, CalendarVariant::Traditional), 32);
+    /// ```
+    pub fn days_in_with_variant(&self, year: u16, variant: CalendarVariant) -> u8 {
+        variant
+            .days_in_month(self.map_to_index(), year as i64)
+            .unwrap_or_default()
+    }
 }
 
 impl fmt::Display for BengaliMonths {
@@ -0,0 +1,469 @@
+//! # Names: Resolving the spellings of the Bengali months and week days
+//! The `names` module finds the Bengali month or week day meant by a name typed in any common way:
+//! the romanized spellings (`Boishakh`, `Baisakh`, `Baishakh`), the Bengali script with its variants,
+//! the abbreviations and the English week days. Names that are not in the table are matched to the
+//! closest spelling, and the `NameMatch` says how close it was.
+//!
+//! A misspelt name is only matched when it is clearly one month or week day: the spellings shorter
+//! than four characters must match exactly, the number of edits allowed grows with the length of
+//! the name, and a month name that is as close to another month or to an English month is rejected.
+
+use crate::days::{BengaliWeekDays, WeekDayError};
+use crate::months::{BengaliMonths, EnglishMonths, MonthError};
+
+/// The lowest confidence of a match that is accepted
+pub const MIN_CONFIDENCE: f64 = 0.75;
+
+/// The length of the shortest spelling that a misspelt name can be matched to
+const MIN_FUZZY_LENGTH: usize = 4;
+
+/// The spellings of the Bengali months
+const MONTH_NAMES: [(BengaliMonths, &[&str]); 12] = [
+    (
+        BengaliMonths::Baishakh,
+        &[
+            "baishakh",
+            "boishakh",
+            "baisakh",
+            "boisakh",
+            "baishak",
+            "boishak",
+            "vaishakh",
+            "vaisakh",
+            "bai",
+            "boi",
+            "বৈশাখ",
+            "বোশেখ",
+        ],
+    ),
+    (
+        BengaliMonths::Jestha,
+        &[
+            "jestha",
+            "joishtho",
+            "jyoishtho",
+            "jyaistha",
+            "jaistha",
+            "jyeshtha",
+            "jyestha",
+            "joishto",
+            "jeshtho",
+            "jes",
+            "joi",
+            "জ্যৈষ্ঠ",
+            "জষ্টি",
+        ],
+    ),
+    (
+        BengaliMonths::Ashad,
+        &[
+            "ashad",
+            "asharh",
+            "ashar",
+            "asar",
+            "asadh",
+            "ashadh",
+            "ashada",
+            "আষাঢ়",
+        ],
+    ),
+    (
+        BengaliMonths::Shrawan,
+        &[
+            "shrawan",
+            "srabon",
+            "shrabon",
+            "sraban",
+            "shraban",
+            "shravan",
+            "sravan",
+            "srab",
+            "শ্রাবণ",
+            "শাওন",
+        ],
+    ),
+    (
+        BengaliMonths::Bhadra,
+        &[
+            "bhadra",
+            "bhadro",
+            "vadro",
+            "bhaddro",
+            "bhadrapad",
+            "bha",
+            "ভাদ্র",
+            "ভাদর",
+        ],
+    ),
+    (
+        BengaliMonths::Ashwin,
+        &[
+            "ashwin",
+            "ashshin",
+            "ashin",
+            "aswin",
+            "ashvin",
+            "ashwina",
+            "আশ্বিন",
+            "আশিন",
+        ],
+    ),
+    (
+        BengaliMonths::Kartik,
+        &[
+            "kartik",
+            "kartick",
+            "kartika",
+            "karthik",
+            "kartic",
+            "kar",
+            "কার্তিক",
+        ],
+    ),
+    (
+        BengaliMonths::Ogrohaeon,
+        &[
+            "ogrohaeon",
+            "agrahayan",
+            "ogrohayon",
+            "agrahayon",
+            "ogrohayan",
+            "agrahayana",
+            "ograhayan",
+            "agrohayon",
+            "agr",
+            "ogr",
+            "অগ্রহায়ণ",
+            "অঘ্রাণ",
+        ],
+    ),
+    (
+        BengaliMonths::Poush,
+        &["poush", "pous", "paush", "pausha", "poh", "পৌষ", "পোষ"],
+    ),
+    (BengaliMonths::Magh, &["magh", "mag", "magha", "মাঘ"]),
+    (
+        BengaliMonths::Falgun,
+        &[
+            "falgun",
+            "phalgun",
+            "falgoon",
+            "phalguna",
+            "fagun",
+            "fal",
+            "ফাল্গুন",
+            "ফাগুন",
+        ],
+    ),
+    (
+        BengaliMonths::Chaitra,
+        &[
+            "chaitra",
+            "choitro",
+            "choitra",
+            "chaitro",
+            "chait",
+            "chai",
+            "choi",
+            "চৈত্র",
+            "চোত",
+        ],
+    ),
+];
+
+/// The spellings of the Bengali week days
+const WEEK_DAY_NAMES: [(BengaliWeekDays, &[&str]); 7] = [
+    (
+        BengaliWeekDays::Robibar,
+        &[
+            "robibar",
+            "rabibar",
+            "robibaar",
+            "robivar",
+            "rabivar",
+            "robi",
+            "rabi",
+            "sunday",
+            "sun",
+            "রবিবার",
+            "রবি",
+        ],
+    ),
+    (
+        BengaliWeekDays::Sombar,
+        &[
+            "sombar",
+            "shombar",
+            "somvar",
+            "sombaar",
+            "somabar",
+            "som",
+            "monday",
+            "mon",
+            "সোমবার",
+            "সোম",
+        ],
+    ),
+    (
+        BengaliWeekDays::Mongolbar,
+        &[
+            "mongolbar",
+            "mangalbar",
+            "mongalbar",
+            "mangalvar",
+            "mongol",
+            "mangal",
+            "tuesday",
+            "tue",
+            "মঙ্গলবার",
+            "মঙ্গল",
+        ],
+    ),
+    (
+        BengaliWeekDays::Budhbar,
+        &[
+            "budhbar",
+            "budhvar",
+            "budhbaar",
+            "budbar",
+            "budh",
+            "wednesday",
+            "wed",
+            "বুধবার",
+            "বুধ",
+        ],
+    ),
+    (
+        BengaliWeekDays::Brihoshpotibar,
+        &[
+            "brihoshpotibar",
+            "brihaspatibar",
+            "brihospotibar",
+            "brihaspativar",
+            "bishudbar",
+            "brihoshpoti",
+            "brihaspati",
+            "thursday",
+            "thu",
+            "বৃহস্পতিবার",
+            "বৃহঃ",
+            "বিষ্যুদবার",
+        ],
+    ),
+    (
+        BengaliWeekDays::Shukrobar,
+        &[
+            "shukrobar",
+            "sukrobar",
+            "shukrabar",
+            "shukravar",
+            "sukrabar",
+            "shukro",
+            "shukra",
+            "friday",
+            "fri",
+            "শুক্রবার",
+            "শুক্র",
+        ],
+    ),
+    (
+        BengaliWeekDays::Shonibar,
+        &[
+            "shonibar",
+            "shanibar",
+            "sonibar",
+            "shanivar",
+            "shoni",
+            "shani",
+            "saturday",
+            "sat",
+            "শনিবার",
+            "শনি",
+        ],
+    ),
+];
+
+/// # `NameMatch`: A name resolved to a month or a week day.
+/// The struct holds the value the name was resolved to and how close the name was to its spelling.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NameMatch<T> {
+    /// The month or the week day
+    pub value: T,
+    /// How close the name was, from `MIN_CONFIDENCE` to 1.0 for a known spelling
+    pub confidence: f64,
+}
+
+/// Bring a name to the form used in the tables
+/// # Arguments
+/// * `name` - &str
+/// # Returns
+/// * `Vec<char>` - The name in lower case, without spaces and punctuation, with the Bengali nukta letters decomposed
+fn normalize(name: &str) -> Vec<char> {
+    let mut chars = Vec::new();
+    for c in name.chars().flat_map(char::to_lowercase) {
+        match c {
+            '\u{09DC}' => chars.extend(['\u{09A1}', '\u{09BC}']),
+            '\u{09DD}' => chars.extend(['\u{09A2}', '\u{09BC}']),
+            '\u{09DF}' => chars.extend(['\u{09AF}', '\u{09BC}']),
+            c if c.is_whitespace() || c.is_ascii_punctuation() => {}
+            c => chars.push(c),
+        }
+    }
+    chars
+}
+
+/// Count the edits needed to turn one name into another
+/// # Arguments
+/// * `a` - &[char]
+/// * `b` - &[char]
+/// # Returns
+/// * `usize` - The Levenshtein distance between the names
+fn distance(a: &[char], b: &[char]) -> usize {
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+
+    for (i, a) in a.iter().enumerate() {
+        current[0] = i + 1;
+        for (j, b) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a != b);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[b.len()]
+}
+
+/// Get the number of edits allowed between a misspelt name and a spelling
+/// # Arguments
+/// * `length` - usize, the length of the longer of the two
+/// # Returns
+/// * `usize` - One edit for every four characters, at least one
+fn allowed_edits(length: usize) -> usize {
+    (length / 4).max(1)
+}
+
+/// Find the value with the spelling closest to a name
+/// # Arguments
+/// * `name` - &str
+/// * `table` - &[(T, &[&str])], the values with their spellings
+/// * `rivals` - &[String], the names a misspelt name must not be close to, such as the English months
+/// # Returns
+/// * `Option<NameMatch<T>>` - The closest value, if the name matches one value clearly
+fn resolve<T: Copy + PartialEq>(
+    name: &str,
+    table: &[(T, &[&str])],
+    rivals: &[String],
+) -> Option<NameMatch<T>> {
+    let name = normalize(name);
+    if name.is_empty() {
+        return None;
+    }
+
+    // the edits and the length of the closest spelling, and whether another value is as close
+    let mut best: Option<(usize, usize, T)> = None;
+    let mut ambiguous = false;
+    for (value, spellings) in table {
+        for spelling in spellings.iter() {
+            let spelling = normalize(spelling);
+            let length = name.len().max(spelling.len());
+            let edits = distance(&name, &spelling);
+            if edits > 0 && (spelling.len() < MIN_FUZZY_LENGTH || edits > allowed_edits(length)) {
+                continue;
+            }
+
+            match best {
+                Some((best_edits, _, _)) if best_edits < edits => {}
+                Some((best_edits, _, best_value)) if best_edits == edits => {
+                    ambiguous |= best_value != *value;
+                }
+                _ => {
+                    best = Some((edits, length, *value));
+                    ambiguous = false;
+                }
+            }
+        }
+    }
+
+    let (edits, length, value) = best?;
+    if edits > 0 {
+        let near_rival = rivals
+            .iter()
+            .any(|rival| distance(&name, &normalize(rival)) <= allowed_edits(name.len()));
+        if ambiguous || near_rival {
+            return None;
+        }
+    }
+
+    let confidence = 1.0 - edits as f64 / length as f64;
+    match confidence >= MIN_CONFIDENCE {
+        true => Some(NameMatch { value, confidence }),
+        false => None,
+    }
+}
+
+impl BengaliMonths {
+    /// Find the Bengali month meant by a name
+    /// # Arguments
+    /// * `name` - &str, the month in Latin or Bengali script, in any case
+    /// # Returns
+    /// * `Result<NameMatch<BengaliMonths>, MonthError>` - The month with the confidence of the match
+    /// # Example
+    /// ```
+    /// use ponjika::months::BengaliMonths;
+    /// for name in ["Boishakh", "Baisakh", "BAISHAKH", "বৈশাখ"] {
+    ///     let found = BengaliMonths::resolve(name).unwrap();
+    ///     assert_eq!(found.value, BengaliMonths::Baishakh);
+    ///     assert_eq!(found.confidence, 1.0);
+    /// }
+    /// let found = BengaliMonths::resolve("Agrahayon").unwrap();
+    /// assert_eq!(found.value, BengaliMonths::Ogrohaeon);
+    /// let found = BengaliMonths::resolve("Srabonn").unwrap();
+    /// assert_eq!(found.value, BengaliMonths::Shrawan);
+    /// assert!(found.confidence < 1.0);
+    /// assert!(BengaliMonths::resolve("January").is_err());
+    /// assert!(BengaliMonths::resolve("March").is_err());
+    /// ```
+    /// # Note
+    /// * The spaces, the punctuation and the case of the name are ignored
+    /// * The function will return `MonthError::UnknownMonth` if no spelling is close enough to the name
+    /// * The function will return `MonthError::UnknownMonth` if a misspelt name is as close to two months or close to an English month
+    pub fn resolve(name: &str) -> Result<NameMatch<Self>, MonthError> {
+        let english_months: Vec<String> = (1..=12)
+            .filter_map(|month| EnglishMonths::get_month(month).ok())
+            .flat_map(|month| [month.to_string(), month.get_short_name().to_string()])
+            .collect();
+
+        match resolve(name, &MONTH_NAMES, &english_months) {
+            Some(found) => Ok(found),
+            None => Err(MonthError::UnknownMonth),
+        }
+    }
+}
+
+impl BengaliWeekDays {
+    /// Find the Bengali week day meant by a name
+    /// # Arguments
+    /// * `name` - &str, the week day in Latin or Bengali script, or the English week day, in any case
+    /// # Returns
+    /// * `Result<NameMatch<BengaliWeekDays>, WeekDayError>` - The week day with the confidence of the match
+    /// # Example
+    /// ```
+    /// use ponjika::days::BengaliWeekDays;
+    /// for name in ["Shonibar", "shanibar", "Saturday", "শনি"] {
+    ///     let found = BengaliWeekDays::resolve(name).unwrap();
+    ///     assert_eq!(found.value, BengaliWeekDays::Shonibar);
+    ///     assert_eq!(found.confidence, 1.0);
+    /// }
+    /// assert!(BengaliWeekDays::resolve("baishakh").is_err());
+    /// ```
+    /// # Note
+    /// * The spaces, the punctuation and the case of the name are ignored
+    /// * The function will return `WeekDayError::UnknownWeekDays` if no spelling is close enough to the name or a misspelt name is as close to two week days
+    pub fn resolve(name: &str) -> Result<NameMatch<Self>, WeekDayError> {
+        match resolve(name, &WEEK_DAY_NAMES, &[]) {
+            Some(found) => Ok(found),
+            None => Err(WeekDayError::UnknownWeekDays),
+        }
+    }
+}
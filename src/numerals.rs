@@ -0,0 +1,261 @@
+//! # Numerals: Writing and reading numbers in Bengali digits
+//! The `numerals` module writes numbers in the Bengali digits (০ ১ ২ ৩ ৪ ৫ ৬ ৭ ৮ ৯) and reads them back.
+//! The `BengaliNumeral` trait is implemented for all the integer types, and the numbers can be read
+//! from Bengali, Latin or mixed digits.
+
+use std::fmt;
+use std::str::FromStr;
+
+/// The Bengali digits from zero to nine
+pub const BENGALI_DIGITS: [char; 10] = ['০', '১', '২', '৩', '৪', '৫', '৬', '৭', '৮', '৯'];
+
+/// # `NumeralError`: The error enum for the numerals.
+/// The enum variants are the error messages for the numerals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NumeralError {
+    /// The NoDigits variant is used when the text has no digits.
+    NoDigits,
+    /// The WrongCharacter variant is used when the text has a character that is not a digit.
+    WrongCharacter {
+        /// The position of the character, counted in characters from 0
+        position: usize,
+        /// The character that was found
+        character: char,
+    },
+    /// The OutOfRange variant is used when the number does not fit in the type.
+    OutOfRange,
+}
+
+impl fmt::Display for NumeralError {
+    /// Display the error message
+    /// # Returns
+    /// * `fmt::Result` - The error message
+    /// # Example
+    /// ```
+    /// use ponjika::numerals::NumeralError;
+    /// let error = NumeralError::NoDigits;
+    /// println!("{}", error);
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            NumeralError::NoDigits => write!(f, "NumeralError: The text has no digits"),
+            NumeralError::WrongCharacter {
+                position,
+                character,
+            } => write!(
+                f,
+                "NumeralError: The character '{}' at position {} is not a digit",
+                character, position
+            ),
+            NumeralError::OutOfRange => {
+                write!(f, "NumeralError: The number does not fit in the type")
+            }
+        }
+    }
+}
+
+impl std::error::Error for NumeralError {}
+
+/// # `BengaliNumeral`: The trait for the numbers written in Bengali digits.
+/// The trait is implemented for all the integer types.
+pub trait BengaliNumeral {
+    /// Write the number in Bengali digits
+    /// # Returns
+    /// * `String` - The number in Bengali digits, with a `-` if it is negative
+    /// # Example
+    /// ```
+    /// use ponjika::numerals::BengaliNumeral;
+    /// assert_eq!(1431u16.to_bengali(), "১৪৩১");
+    /// assert_eq!((-25i64).to_bengali(), "-২৫");
+    /// ```
+    fn to_bengali(&self) -> String;
+
+    /// Write the number in Bengali digits with leading zeros
+    /// # Arguments
+    /// * `width` - usize, the smallest number of characters, counting the `-` of a negative number
+    /// # Returns
+    /// * `String` - The number in Bengali digits
+    /// # Example
+    /// ```
+    /// use ponjika::numerals::BengaliNumeral;
+    /// assert_eq!(5u8.to_bengali_padded(2), "০৫");
+    /// assert_eq!(1431u16.to_bengali_padded(2), "১৪৩১");
+    /// assert_eq!((-5i32).to_bengali_padded(3), "-০৫");
+    /// ```
+    fn to_bengali_padded(&self, width: usize) -> String;
+}
+
+// Implement the `BengaliNumeral` trait for the integer types
+macro_rules! bengali_numeral {
+    ($($number:ty),*) => {
+        $(
+            impl BengaliNumeral for $number {
+                fn to_bengali(&self) -> String {
+                    to_bengali_digits(&self.to_string())
+                }
+
+                fn to_bengali_padded(&self, width: usize) -> String {
+                    to_bengali_digits(&format!("{:0width$}", self, width = width))
+                }
+            }
+        )*
+    };
+}
+
+bengali_numeral!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// Get the value of a Bengali or Latin digit
+/// # Arguments
+/// * `c` - char
+/// # Returns
+/// * `Option<u32>` - The value of the digit, or `None` if the character is not a digit
+/// # Example
+/// ```
+/// use ponjika::numerals::digit_value;
+/// assert_eq!(digit_value('৭'), Some(7));
+/// assert_eq!(digit_value('7'), Some(7));
+/// assert_eq!(digit_value('a'), None);
+/// ```
+pub fn digit_value(c: char) -> Option<u32> {
+    match c {
+        '0'..='9' => Some(c as u32 - '0' as u32),
+        '০'..='৯' => Some(c as u32 - '০' as u32),
+        _ => None,
+    }
+}
+
+/// Replace the Latin digits of a text with Bengali digits
+/// # Arguments
+/// * `text` - &str
+/// # Returns
+/// * `String` - The text with Bengali digits, the other characters are kept
+/// # Example
+/// ```
+/// use ponjika::numerals::to_bengali_digits;
+/// assert_eq!(to_bengali_digits("14/04/2024"), "১৪/০৪/২০২৪");
+/// ```
+pub fn to_bengali_digits(text: &str) -> String {
+    text.chars()
+        .map(|c| match c {
+            '0'..='9' => BENGALI_DIGITS[(c as u32 - '0' as u32) as usize],
+            _ => c,
+        })
+        .collect()
+}
+
+/// Replace the Bengali digits of a text with Latin digits
+/// # Arguments
+/// * `text` - &str
+/// # Returns
+/// * `String` - The text with Latin digits, the other characters are kept
+/// # Example
+/// ```
+/// use ponjika::numerals::to_latin_digits;
+/// assert_eq!(to_latin_digits("১৪ এপ্রিল ২০২৪"), "14 এপ্রিল 2024");
+/// ```
+pub fn to_latin_digits(text: &str) -> String {
+    text.chars()
+        .map(|c| match c {
+            '০'..='৯' => char::from(b'0' + (c as u32 - '০' as u32) as u8),
+            _ => c,
+        })
+        .collect()
+}
+
+/// Write a decimal number in Bengali digits
+/// # Arguments
+/// * `number` - f64
+/// * `decimals` - usize, the number of digits after the decimal point
+/// # Returns
+/// * `String` - The number in Bengali digits, rounded to the decimals
+/// # Example
+/// ```
+/// use ponjika::numerals::format_decimal;
+/// assert_eq!(format_decimal(3.14159, 2), "৩.১৪");
+/// assert_eq!(format_decimal(-0.5, 0), "-০");
+/// ```
+pub fn format_decimal(number: f64, decimals: usize) -> String {
+    to_bengali_digits(&format!("{:.*}", decimals, number))
+}
+
+/// Read the digits of a number in Latin digits
+/// # Arguments
+/// * `text` - &str, the number in Bengali, Latin or mixed digits
+/// * `decimal` - bool, `true` if one decimal point is allowed
+/// # Returns
+/// * `Result<String, NumeralError>` - The number in Latin digits
+fn read_digits(text: &str, decimal: bool) -> Result<String, NumeralError> {
+    let mut latin = String::new();
+    let mut digits = 0;
+    let mut point = false;
+
+    for (position, c) in text.chars().enumerate() {
+        match (c, digit_value(c)) {
+            (_, Some(digit)) => {
+                latin.push(char::from(b'0' + digit as u8));
+                digits += 1;
+            }
+            ('-' | '+', None) if position == 0 => latin.push(c),
+            ('.', None) if decimal && !point => {
+                latin.push(c);
+                point = true;
+            }
+            (character, None) => {
+                return Err(NumeralError::WrongCharacter {
+                    position,
+                    character,
+                })
+            }
+        }
+    }
+
+    match digits {
+        0 => Err(NumeralError::NoDigits),
+        _ => Ok(latin),
+    }
+}
+
+/// Read an integer from Bengali, Latin or mixed digits
+/// # Arguments
+/// * `text` - &str, the number with an optional `-` or `+`
+/// # Returns
+/// * `Result<T, NumeralError>` - The number
+/// # Example
+/// ```
+/// use ponjika::numerals::parse_number;
+/// assert_eq!(parse_number::<u16>("১৪৩১"), Ok(1431));
+/// assert_eq!(parse_number::<i32>("-২5"), Ok(-25));
+/// assert_eq!(parse_number::<u8>("০০৭"), Ok(7));
+/// assert!(parse_number::<u8>("৩০০").is_err());
+/// ```
+/// # Note
+/// * The function will return `NumeralError::NoDigits` if the text has no digits
+/// * The function will return `NumeralError::WrongCharacter` with the position of the first character that is not a digit
+/// * The function will return `NumeralError::OutOfRange` if the number does not fit in the type
+pub fn parse_number<T: FromStr>(text: &str) -> Result<T, NumeralError> {
+    match read_digits(text, false)?.parse::<T>() {
+        Ok(number) => Ok(number),
+        Err(_) => Err(NumeralError::OutOfRange),
+    }
+}
+
+/// Read a decimal number from Bengali, Latin or mixed digits
+/// # Arguments
+/// * `text` - &str, the number with an optional `-` or `+` and an optional decimal point
+/// # Returns
+/// * `Result<f64, NumeralError>` - The number
+/// # Example
+/// ```
+/// use ponjika::numerals::parse_decimal;
+/// assert_eq!(parse_decimal("৩.১৪"), Ok(3.14));
+/// assert!(parse_decimal("৩.১.৪").is_err());
+/// ```
+/// # Note
+/// * The function will return `NumeralError::NoDigits` if the text has no digits
+/// * The function will return `NumeralError::WrongCharacter` with the position of the first character that is not a digit
+pub fn parse_decimal(text: &str) -> Result<f64, NumeralError> {
+    match read_digits(text, true)?.parse::<f64>() {
+        Ok(number) => Ok(number),
+        Err(_) => Err(NumeralError::OutOfRange),
+    }
+}
@@ -0,0 +1,221 @@
+//! # Ordinal: The position of a day in its year
+//! The `ordinal` module counts the days and the weeks of the `EnglishDate` and `BengaliDate` years.
+//! The first day of the year, 1st January or 1 Baishakh, is the day 1.
+//! The weeks start on a selected week day, Shonibar (Saturday) in Bangladesh and Robibar (Sunday) in West Bengal.
+//! The week 1 is the week of the first day of the year, so it can be shorter than seven days.
+
+use crate::calendar::CalendarVariant;
+use crate::date::{BengaliDate, DateError, DateField, EnglishDate};
+use crate::days::BengaliWeekDays;
+use crate::months::{BengaliMonths, EnglishMonths};
+use crate::rata_die::RataDie;
+
+/// Get the week of a day in its year
+/// # Arguments
+/// * `ordinal` - u16, the day of the year
+/// * `first_of_year` - RataDie, the first day of the year
+/// * `first_day` - BengaliWeekDays, the day a week starts on
+/// # Returns
+/// * `Result<u8, DateError>` - The week of the year, counted from 1
+fn week_of(
+    ordinal: u16,
+    first_of_year: RataDie,
+    first_day: BengaliWeekDays,
+) -> Result<u8, DateError> {
+    let first_day = match first_day.get_day_number() {
+        Ok(first_day) => first_day,
+        Err(err) => return Err(DateError::WrongWeekDay(err)),
+    };
+
+    // the days of the week 1 that fall in the previous year
+    let lead = (first_of_year.get_day_number() - first_day).rem_euclid(7);
+    Ok(((ordinal as i64 - 1 + lead) / 7 + 1) as u8)
+}
+
+impl EnglishDate {
+    /// Get the day of the year of the selected date
+    /// # Returns
+    /// * `u16` - The day of the year, 1st January is the day 1
+    /// # Example
+    /// ```
+    /// use ponjika::date::EnglishDate;
+    /// use ponjika::months::EnglishMonths;
+    /// let date = EnglishDate::create_date(31, EnglishMonths::December, 2024).unwrap();
+    /// assert_eq!(date.ordinal(), 366);
+    /// ```
+    pub fn ordinal(&self) -> u16 {
+        let (_, _, year) = self.get_date();
+        let first = EnglishDate::create_date(1, EnglishMonths::January, year).unwrap();
+        (self.to_rata_die().get_day_number() - first.to_rata_die().get_day_number() + 1) as u16
+    }
+
+    /// Create an English date from the day of the year
+    /// # Arguments
+    /// * `year` - u16
+    /// * `ordinal` - u16, the day of the year, 1st January is the day 1
+    /// # Returns
+    /// * `Result<EnglishDate, DateError>` - The English date
+    /// # Example
+    /// ```
+    /// use ponjika::date::EnglishDate;
+    /// let date = EnglishDate::from_ordinal(2024, 60).unwrap();
+    /// assert_eq!(date.get_date(), (29, 2, 2024));
+    /// ```
+    /// # Note
+    /// * The function will return `DateError::OutOfRange` if the year has fewer days than the ordinal
+    /// * The function will return `DateError::OutOfRange` if the year is out of the supported range
+    pub fn from_ordinal(year: u16, ordinal: u16) -> Result<Self, DateError> {
+        let first = EnglishDate::create_date(1, EnglishMonths::January, year)?;
+        let days = EnglishDate::days_in_year(year);
+        if !(1..=days).contains(&ordinal) {
+            return Err(DateError::out_of_range(
+                DateField::Ordinal,
+                ordinal,
+                1,
+                days,
+            ));
+        }
+
+        EnglishDate::from_rata_die(RataDie::new(
+            first.to_rata_die().get_day_number() + ordinal as i64 - 1,
+        ))
+    }
+
+    /// Get the week of the year of the selected date
+    /// # Arguments
+    /// * `first_day` - BengaliWeekDays, the day a week starts on
+    /// # Returns
+    /// * `Result<u8, DateError>` - The week of the year, the week of 1st January is the week 1
+    /// # Example
+    /// ```
+    /// use ponjika::date::EnglishDate;
+    /// use ponjika::days::BengaliWeekDays;
+    /// use ponjika::months::EnglishMonths;
+    /// // 1st January 2024 was a Monday
+    /// let date = EnglishDate::create_date(7, EnglishMonths::January, 2024).unwrap();
+    /// assert_eq!(date.week_of_year(BengaliWeekDays::Robibar).unwrap(), 2);
+    /// assert_eq!(date.week_of_year(BengaliWeekDays::Sombar).unwrap(), 1);
+    /// ```
+    /// # Note
+    /// * The function will return `DateError::WrongWeekDay` if the week day is `BengaliWeekDays::UnImplemented`
+    pub fn week_of_year(&self, first_day: BengaliWeekDays) -> Result<u8, DateError> {
+        let (_, _, year) = self.get_date();
+        let first = EnglishDate::create_date(1, EnglishMonths::January, year)?;
+        week_of(self.ordinal(), first.to_rata_die(), first_day)
+    }
+}
+
+impl BengaliDate {
+    /// Get the day of the year of the selected date
+    /// # Returns
+    /// * `u16` - The day of the year, 1 Baishakh is the day 1
+    /// # Example
+    /// ```
+    /// use ponjika::date::BengaliDate;
+    /// use ponjika::months::BengaliMonths;
+    /// let date = BengaliDate::create_date(1, BengaliMonths::Ashwin, 1431).unwrap();
+    /// assert_eq!(date.ordinal(), 156);
+    /// ```
+    /// # Note
+    /// * The function will use the calendar variant of the date
+    pub fn ordinal(&self) -> u16 {
+        let (_, _, year) = self.get_date();
+        let first = BengaliDate::create_date_with_variant(
+            1,
+            BengaliMonths::Baishakh,
+            year,
+            self.get_variant(),
+        )
+        .unwrap();
+        (self.to_rata_die().get_day_number() - first.to_rata_die().get_day_number() + 1) as u16
+    }
+
+    /// Create a Bengali date from the day of the year
+    /// # Arguments
+    /// * `year` - u16
+    /// * `ordinal` - u16, the day of the year, 1 Baishakh is the day 1
+    /// # Returns
+    /// * `Result<BengaliDate, DateError>` - The Bengali date with its week day
+    /// # Example
+    /// ```
+    /// use ponjika::date::BengaliDate;
+    /// let date = BengaliDate::from_ordinal(1431, 156).unwrap();
+    /// assert_eq!(date.get_date(), (1, 6, 1431));
+    /// ```
+    /// # Note
+    /// * The function will use the `CalendarVariant::Revised2019` rules
+    /// * The function will return `DateError::OutOfRange` if the year has fewer days than the ordinal
+    pub fn from_ordinal(year: u16, ordinal: u16) -> Result<Self, DateError> {
+        Self::from_ordinal_with_variant(year, ordinal, CalendarVariant::default())
+    }
+
+    /// Create a Bengali date from the day of the year in a calendar variant
+    /// # Arguments
+    /// * `year` - u16
+    /// * `ordinal` - u16, the day of the year, 1 Baishakh is the day 1
+    /// * `variant` - CalendarVariant
+    /// # Returns
+    /// * `Result<BengaliDate, DateError>` - The Bengali date with its week day
+    /// # Example
+    /// ```
+    /// use ponjika::calendar::CalendarVariant;
+    /// use ponjika::date::BengaliDate;
+    /// // Shrawan 1431 has 32 days in the traditional calendar
+    /// let date = BengaliDate::from_ordinal_with_variant(1431, 126, CalendarVariant::Traditional).unwrap();
+    /// assert_eq!(date.get_date(), (32, 4, 1431));
+    /// ```
+    /// # Note
+    /// * The function will return `DateError::OutOfRange` if the year has fewer days than the ordinal
+    /// * The function will return `DateError::OutOfRange` if the year is out of the supported range
+    pub fn from_ordinal_with_variant(
+        year: u16,
+        ordinal: u16,
+        variant: CalendarVariant,
+    ) -> Result<Self, DateError> {
+        let first =
+            BengaliDate::create_date_with_variant(1, BengaliMonths::Baishakh, year, variant)?;
+        let days = BengaliDate::days_in_year_with_variant(year, variant);
+        if !(1..=days).contains(&ordinal) {
+            return Err(DateError::out_of_range(
+                DateField::Ordinal,
+                ordinal,
+                1,
+                days,
+            ));
+        }
+
+        BengaliDate::from_rata_die(
+            RataDie::new(first.to_rata_die().get_day_number() + ordinal as i64 - 1),
+            variant,
+        )
+    }
+
+    /// Get the week of the year of the selected date
+    /// # Arguments
+    /// * `first_day` - BengaliWeekDays, the day a week starts on
+    /// # Returns
+    /// * `Result<u8, DateError>` - The week of the year, the week of 1 Baishakh is the week 1
+    /// # Example
+    /// ```
+    /// use ponjika::date::BengaliDate;
+    /// use ponjika::days::BengaliWeekDays;
+    /// use ponjika::months::BengaliMonths;
+    /// // 1 Baishakh 1431 was a Robibar (Sunday)
+    /// let date = BengaliDate::create_date(7, BengaliMonths::Baishakh, 1431).unwrap();
+    /// assert_eq!(date.week_of_year(BengaliWeekDays::Shonibar).unwrap(), 2);
+    /// assert_eq!(date.week_of_year(BengaliWeekDays::Robibar).unwrap(), 1);
+    /// ```
+    /// # Note
+    /// * The function will use the calendar variant of the date
+    /// * The function will return `DateError::WrongWeekDay` if the week day is `BengaliWeekDays::UnImplemented`
+    pub fn week_of_year(&self, first_day: BengaliWeekDays) -> Result<u8, DateError> {
+        let (_, _, year) = self.get_date();
+        let first = BengaliDate::create_date_with_variant(
+            1,
+            BengaliMonths::Baishakh,
+            year,
+            self.get_variant(),
+        )?;
+        week_of(self.ordinal(), first.to_rata_die(), first_day)
+    }
+}
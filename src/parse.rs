@@ -0,0 +1,354 @@
+//! # Parse: Reading Bengali dates from text
+//! The `parse` module reads Bengali dates written in Bengali or Latin digits, with the Bengali month
+//! and week day names. It uses the same specifiers as the `format` module.
+
+use std::str::FromStr;
+
+use crate::calendar::CalendarVariant;
+use crate::date::{BengaliDate, DateError, DateField};
+use crate::days::BengaliWeekDays;
+use crate::format::BENGALI_ERA;
+use crate::months::BengaliMonths;
+use crate::numerals;
+
+/// The patterns tried by `BengaliDate::from_str`, in order
+const PATTERNS: [&str; 10] = [
+    "%A, %d %B %Y",
+    "%A %d %B %Y",
+    "%d %B %Y",
+    "%d %B, %Y",
+    "%B %d, %Y",
+    "%A, %d/%m/%Y",
+    "%d/%m/%Y",
+    "%d-%m-%Y",
+    "%d.%m.%Y",
+    "%Y-%m-%d",
+];
+
+/// The week days in the order of their numbers
+const WEEK_DAYS: [BengaliWeekDays; 7] = [
+    BengaliWeekDays::Robibar,
+    BengaliWeekDays::Sombar,
+    BengaliWeekDays::Mongolbar,
+    BengaliWeekDays::Budhbar,
+    BengaliWeekDays::Brihoshpotibar,
+    BengaliWeekDays::Shukrobar,
+    BengaliWeekDays::Shonibar,
+];
+
+/// The input that is being read, with the position of the next character
+struct Reader {
+    input: Vec<char>,
+    position: usize,
+}
+
+impl Reader {
+    /// Create the error at the current position
+    fn error(&self) -> DateError {
+        DateError::ParseError {
+            position: self.position,
+            cause: None,
+        }
+    }
+
+    /// Skip the white space and return the number of characters skipped
+    fn skip_spaces(&mut self) -> usize {
+        let start = self.position;
+        while self.position < self.input.len() && self.input[self.position].is_whitespace() {
+            self.position += 1;
+        }
+        self.position - start
+    }
+
+    /// Read a number of up to `max_digits` Bengali or Latin digits
+    fn read_number(&mut self, max_digits: usize) -> Result<u16, DateError> {
+        let start = self.position;
+        let mut number: u16 = 0;
+        while self.position < self.input.len() && self.position - start < max_digits {
+            let digit = match numerals::digit_value(self.input[self.position]) {
+                Some(digit) => digit,
+                None => break,
+            };
+            number = number * 10 + digit as u16;
+            self.position += 1;
+        }
+
+        match self.position == start {
+            true => Err(self.error()),
+            false => Ok(number),
+        }
+    }
+
+    /// Read the given text
+    fn read_text(&mut self, text: &str) -> Result<(), DateError> {
+        for c in text.chars() {
+            match self.input.get(self.position) {
+                Some(&next) if next == c => self.position += 1,
+                _ => return Err(self.error()),
+            }
+        }
+        Ok(())
+    }
+
+    /// Read the longest of the given names and return its value
+    fn read_name<T: Copy>(&mut self, names: &[(String, T)]) -> Result<T, DateError> {
+        let mut longest: Option<(usize, T)> = None;
+        for (name, value) in names {
+            let length = name.chars().count();
+            let matches = self.input.len() >= self.position + length
+                && name
+                    .chars()
+                    .zip(&self.input[self.position..])
+                    .all(|(a, &b)| a == b);
+            match longest {
+                Some((longest_length, _)) if longest_length >= length => {}
+                _ if matches => longest = Some((length, *value)),
+                _ => {}
+            }
+        }
+
+        match longest {
+            Some((length, value)) => {
+                self.position += length;
+                Ok(value)
+            }
+            None => Err(self.error()),
+        }
+    }
+}
+
+/// Point an error of the date to the position of the part that caused it
+/// # Arguments
+/// * `err` - DateError, the error of the date
+/// * `positions` - (usize, usize, usize, Option<usize>), the positions of the day, the month, the year and the week day
+/// # Returns
+/// * `DateError` - `DateError::ParseError` with the error as the cause
+fn at_position(err: DateError, positions: (usize, usize, usize, Option<usize>)) -> DateError {
+    let (day, month, year, week_day) = positions;
+    let position = match err {
+        DateError::OutOfRange {
+            field: DateField::Year,
+            ..
+        } => year,
+        DateError::OutOfRange {
+            field: DateField::Month,
+            ..
+        }
+        | DateError::WrongMonth(_) => month,
+        DateError::WeekDayMismatch { .. } => week_day.unwrap_or(day),
+        _ => day,
+    };
+
+    DateError::ParseError {
+        position,
+        cause: Some(Box::new(err)),
+    }
+}
+
+impl BengaliDate {
+    /// Read a Bengali date from the input with a pattern
+    /// # Arguments
+    /// * `input` - &str, the date in Bengali or Latin digits
+    /// * `pattern` - &str, the layout of the input, see the `format` module for the specifiers
+    /// # Returns
+    /// * `Result<BengaliDate, DateError>` - The Bengali date
+    /// # Example
+    /// ```
+    /// use ponjika::date::BengaliDate;
+    /// let date = BengaliDate::parse("২৫/০৬/১৪১৭", "%d/%m/%Y").unwrap();
+    /// assert_eq!(date.get_date(), (25, 6, 1417));
+    /// let date = BengaliDate::parse("বৈশাখ ১, ১৪৩১ বঙ্গাব্দ", "%B %d, %Y %E").unwrap();
+    /// assert_eq!(date.get_date(), (1, 1, 1431));
+    /// assert!(BengaliDate::parse("সোমবার, ১ বৈশাখ ১৪৩১", "%A, %d %B %Y").is_err());
+    /// ```
+    /// # Note
+    /// * `%d`, `%m` and `%Y` read the numbers in Bengali or Latin digits, and the `-`, `O` and `L` flags are allowed
+    /// * `%B` and `%b` read the month name, and `%A` and `%a` the full or the short week day name
+    /// * `%E` reads the era suffix, `%F` reads `%Y-%m-%d`, and a space in the pattern reads one or more spaces
+    /// * The function will return `DateError::WrongPattern` if the pattern has an unknown specifier or does not have the day, the month and the year
+    /// * The function will return `DateError::ParseError` with the position in the input if the input does not match the pattern
+    /// * The function will return `DateError::ParseError` with the date error as the cause if the date is not valid or does not fall on the week day
+    pub fn parse(input: &str, pattern: &str) -> Result<Self, DateError> {
+        Self::parse_with_variant(input, pattern, CalendarVariant::default())
+    }
+
+    /// Read a Bengali date of a variant from the input with a pattern
+    /// # Arguments
+    /// * `input` - &str, the date in Bengali or Latin digits
+    /// * `pattern` - &str, the layout of the input, see the `format` module for the specifiers
+    /// * `variant` - CalendarVariant
+    /// # Returns
+    /// * `Result<BengaliDate, DateError>` - The Bengali date
+    /// # Example
+    /// ```
+    /// use ponjika::calendar::CalendarVariant;
+    /// use ponjika::date::BengaliDate;
+    /// let date = BengaliDate::parse_with_variant("32/4/1431", "%d/%m/%Y", CalendarVariant::Traditional);
+    /// assert!(date.is_ok());
+    /// let date = BengaliDate::parse_with_variant("32/4/1431", "%d/%m/%Y", CalendarVariant::Revised2019);
+    /// assert!(date.is_err());
+    /// ```
+    /// # Note
+    /// * The function will return the same errors as `BengaliDate::parse`
+    pub fn parse_with_variant(
+        input: &str,
+        pattern: &str,
+        variant: CalendarVariant,
+    ) -> Result<Self, DateError> {
+        let mut reader = Reader {
+            input: input.chars().collect(),
+            position: 0,
+        };
+        let months: Vec<(String, u8)> = (1..=12)
+            .filter_map(|month| BengaliMonths::get_month(month).ok())
+            .map(|month| (month.to_string(), month.map_to_index()))
+            .collect();
+        let week_days: Vec<(String, BengaliWeekDays)> = WEEK_DAYS
+            .iter()
+            .flat_map(|&week_day| {
+                [
+                    (week_day.to_string(), week_day),
+                    (week_day.get_short_name().to_string(), week_day),
+                ]
+            })
+            .collect();
+
+        let mut day: Option<(u8, usize)> = None;
+        let mut month: Option<(u8, usize)> = None;
+        let mut year: Option<(u16, usize)> = None;
+        let mut week_day: Option<(BengaliWeekDays, usize)> = None;
+
+        let mut chars = pattern.chars().enumerate();
+        while let Some((position, c)) = chars.next() {
+            if c.is_whitespace() {
+                if reader.skip_spaces() == 0 {
+                    return Err(reader.error());
+                }
+                continue;
+            }
+            if c != '%' {
+                reader.read_text(c.encode_utf8(&mut [0; 4]))?;
+                continue;
+            }
+
+            let specifier = loop {
+                match chars.next() {
+                    Some((_, '-' | 'O' | 'L')) => {}
+                    Some((_, specifier)) => break specifier,
+                    None => return Err(DateError::WrongPattern { position }),
+                }
+            };
+
+            let start = reader.position;
+            match specifier {
+                'd' => day = Some((reader.read_number(2)? as u8, start)),
+                'm' => month = Some((reader.read_number(2)? as u8, start)),
+                'Y' => year = Some((reader.read_number(4)?, start)),
+                'F' => {
+                    year = Some((reader.read_number(4)?, start));
+                    reader.read_text("-")?;
+                    let month_start = reader.position;
+                    month = Some((reader.read_number(2)? as u8, month_start));
+                    reader.read_text("-")?;
+                    let day_start = reader.position;
+                    day = Some((reader.read_number(2)? as u8, day_start));
+                }
+                'B' | 'b' => month = Some((reader.read_name(&months)?, start)),
+                'A' | 'a' => week_day = Some((reader.read_name(&week_days)?, start)),
+                'E' => reader.read_text(BENGALI_ERA)?,
+                '%' => reader.read_text("%")?,
+                _ => return Err(DateError::WrongPattern { position }),
+            }
+        }
+
+        reader.skip_spaces();
+        if reader.position < reader.input.len() {
+            return Err(reader.error());
+        }
+
+        let ((day, day_position), (month, month_position), (year, year_position)) =
+            match (day, month, year) {
+                (Some(day), Some(month), Some(year)) => (day, month, year),
+                _ => {
+                    return Err(DateError::WrongPattern {
+                        position: pattern.chars().count(),
+                    })
+                }
+            };
+        let positions = (
+            day_position,
+            month_position,
+            year_position,
+            week_day.map(|(_, position)| position),
+        );
+
+        let month = match BengaliMonths::get_month(month) {
+            Ok(month) => month,
+            Err(err) => return Err(at_position(DateError::WrongMonth(err), positions)),
+        };
+        let date = match week_day {
+            Some((week_day, _)) => {
+                Self::create_date_with_weekday_and_variant(day, week_day, month, year, variant)
+            }
+            None => Self::create_date_with_variant(day, month, year, variant),
+        };
+
+        date.map_err(|err| at_position(err, positions))
+    }
+}
+
+impl FromStr for BengaliDate {
+    type Err = DateError;
+
+    /// Read a Bengali date written in one of the common layouts
+    /// # Arguments
+    /// * `s` - &str, the date in Bengali or Latin digits
+    /// # Returns
+    /// * `Result<BengaliDate, DateError>` - The Bengali date
+    /// # Example
+    /// ```
+    /// use ponjika::date::BengaliDate;
+    /// let date: BengaliDate = "২৫ আশ্বিন ১৪১৭".parse().unwrap();
+    /// assert_eq!(date.get_date(), (25, 6, 1417));
+    /// let date: BengaliDate = "রবিবার, ২৫ আশ্বিন ১৪১৭".parse().unwrap();
+    /// assert_eq!(date.get_date(), (25, 6, 1417));
+    /// assert!("সোমবার, ২৫ আশ্বিন ১৪১৭".parse::<BengaliDate>().is_err());
+    /// ```
+    /// # Note
+    /// * The layouts are the day, the month name and the year with an optional week day (`রবিবার, ১ বৈশাখ ১৪৩১`), the month name first (`বৈশাখ ১, ১৪৩১`), and the numbers with `/`, `-` or `.` (`১/১/১৪৩১`, `১৪৩১-০১-০১`)
+    /// * The year can be followed by the era suffix `বঙ্গাব্দ`
+    /// * The function will return the error of the layout that read the most of the input
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim_end();
+        let input = match trimmed.strip_suffix(BENGALI_ERA) {
+            Some(input) => input.trim_end(),
+            None => trimmed,
+        };
+
+        let mut best: Option<DateError> = None;
+        for pattern in PATTERNS {
+            let err = match Self::parse(input, pattern) {
+                Ok(date) => return Ok(date),
+                Err(err) => err,
+            };
+
+            // an invalid date matched the layout, so it is a better error than a layout that did not match
+            let rank = |err: &DateError| match err {
+                DateError::ParseError { position, cause } => (cause.is_some(), *position),
+                _ => (false, 0),
+            };
+            best = match best {
+                Some(best) if rank(&best) >= rank(&err) => Some(best),
+                _ => Some(err),
+            };
+        }
+
+        match best {
+            Some(err) => Err(err),
+            None => Err(DateError::ParseError {
+                position: 0,
+                cause: None,
+            }),
+        }
+    }
+}
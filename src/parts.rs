@@ -0,0 +1,249 @@
+//! # Parts: The typed parts of a date
+//! The `parts` module provides the `DateParts` struct, the numbers and the typed names of a date.
+//! The strings of the parts are only made when the struct is displayed.
+
+use std::fmt;
+
+use crate::calendar::{self, CalendarVariant};
+use crate::date::{BengaliDate, Date, DateError, EnglishDate, JulianDate};
+use crate::days::WeekDays;
+use crate::months::{BengaliMonths, EnglishMonths, Month};
+use crate::numerals::BengaliNumeral;
+use crate::rata_die::RataDie;
+use crate::season::BengaliSeasons;
+
+/// # `DateParts`: The parts of a date.
+/// The struct holds the numbers of the date with the typed month, week day and season.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DateParts {
+    /// The day of the month, counted from 1
+    pub day: u8,
+    /// The month of the year, counted from 1
+    pub month: u8,
+    /// The year
+    pub year: u16,
+    /// The month as `Month::English` or `Month::Bengali`
+    pub month_name: Month,
+    /// The week day as `WeekDays::English` or `WeekDays::Bengali`
+    pub week_day: WeekDays,
+    /// The Bengali season of the day
+    pub season: BengaliSeasons,
+}
+
+/// Get the Bengali season of a day in any calendar
+/// # Arguments
+/// * `rata_die` - RataDie
+/// * `variant` - CalendarVariant, the rules of the Bengali date of the day
+/// # Returns
+/// * `BengaliSeasons` - The season of the Bengali month of the day
+fn season_of(rata_die: RataDie, variant: CalendarVariant) -> BengaliSeasons {
+    let (_, month, _) = calendar::bengali_from_fixed(rata_die.get_day_number(), variant);
+    BengaliSeasons::from_month(month)
+}
+
+impl fmt::Display for DateParts {
+    /// Display the date the same way as the date it was taken from
+    /// # Returns
+    /// * `fmt::Result` - The date with the week day, in Bengali digits for a Bengali date
+    /// # Example
+    /// ```
+    /// use ponjika::date::BengaliDate;
+    /// use ponjika::months::BengaliMonths;
+    /// let date = BengaliDate::create_date(1, BengaliMonths::Baishakh, 1431).unwrap();
+    /// assert_eq!(date.parts().to_string(), "রবিবার, ১ বৈশাখ ১৪৩১");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let week_day = match self.week_day.get_week_day() {
+            Ok(week_day) => week_day,
+            Err(_) => return Err(fmt::Error),
+        };
+        let month = match self.month_name.get_month_name() {
+            Ok(month) => month,
+            Err(_) => return Err(fmt::Error),
+        };
+
+        match self.month_name {
+            Month::Bengali(_) => write!(
+                f,
+                "{}, {} {} {}",
+                week_day,
+                self.day.to_bengali(),
+                month,
+                self.year.to_bengali()
+            ),
+            _ => write!(f, "{}, {} {} {}", week_day, self.day, month, self.year),
+        }
+    }
+}
+
+impl EnglishDate {
+    /// Get the typed parts of the selected date
+    /// # Returns
+    /// * `DateParts` - The numbers, the month, the week day and the season of the date
+    /// # Example
+    /// ```
+    /// use ponjika::date::EnglishDate;
+    /// use ponjika::days::{EnglishWeekDays, WeekDays};
+    /// use ponjika::months::{EnglishMonths, Month};
+    /// use ponjika::season::BengaliSeasons;
+    /// let date = EnglishDate::create_date(21, EnglishMonths::February, 2024).unwrap();
+    /// let parts = date.parts();
+    /// assert_eq!((parts.day, parts.month, parts.year), (21, 2, 2024));
+    /// assert_eq!(parts.month_name, Month::English(EnglishMonths::February));
+    /// assert_eq!(parts.week_day, WeekDays::English(EnglishWeekDays::Wednesday));
+    /// assert_eq!(parts.season, BengaliSeasons::Bashonto);
+    /// ```
+    /// # Note
+    /// * The season is the season of the Bengali date on the same day
+    /// * The function will use the `CalendarVariant::Revised2019` rules
+    pub fn parts(&self) -> DateParts {
+        self.parts_with_variant(CalendarVariant::default())
+    }
+
+    /// Get the typed parts of the selected date with the season of a calendar variant
+    /// # Arguments
+    /// * `variant` - CalendarVariant, the rules of the Bengali date that gives the season
+    /// # Returns
+    /// * `DateParts` - The numbers, the month, the week day and the season of the date
+    /// # Example
+    /// ```
+    /// use ponjika::calendar::CalendarVariant;
+    /// use ponjika::date::EnglishDate;
+    /// use ponjika::months::EnglishMonths;
+    /// use ponjika::season::BengaliSeasons;
+    /// let date = EnglishDate::create_date(14, EnglishMonths::April, 2025).unwrap();
+    /// assert_eq!(date.parts_with_variant(CalendarVariant::Revised2019).season, BengaliSeasons::Grishmo);
+    /// assert_eq!(date.parts_with_variant(CalendarVariant::Traditional).season, BengaliSeasons::Bashonto);
+    /// ```
+    /// # Note
+    /// * The season is the season of the Bengali date of the variant on the same day
+    pub fn parts_with_variant(&self, variant: CalendarVariant) -> DateParts {
+        let (day, month, year) = self.get_date();
+        let rata_die = self.to_rata_die();
+
+        DateParts {
+            day,
+            month,
+            year,
+            month_name: match EnglishMonths::get_month(month) {
+                Ok(month) => Month::English(month),
+                Err(_) => Month::Unknown,
+            },
+            week_day: WeekDays::English(rata_die.get_english_week_day()),
+            season: season_of(rata_die, variant),
+        }
+    }
+}
+
+impl JulianDate {
+    /// Get the typed parts of the selected date
+    /// # Returns
+    /// * `DateParts` - The numbers, the month, the week day and the season of the date
+    /// # Example
+    /// ```
+    /// use ponjika::date::JulianDate;
+    /// use ponjika::months::EnglishMonths;
+    /// let date = JulianDate::create_date(4, EnglishMonths::April, 1600).unwrap();
+    /// assert_eq!(date.parts().to_string(), "Friday, 4 April 1600");
+    /// ```
+    /// # Note
+    /// * The season is the season of the Bengali date on the same day
+    /// * The function will use the `CalendarVariant::Revised2019` rules
+    pub fn parts(&self) -> DateParts {
+        self.parts_with_variant(CalendarVariant::default())
+    }
+
+    /// Get the typed parts of the selected date with the season of a calendar variant
+    /// # Arguments
+    /// * `variant` - CalendarVariant, the rules of the Bengali date that gives the season
+    /// # Returns
+    /// * `DateParts` - The numbers, the month, the week day and the season of the date
+    /// # Example
+    /// ```
+    /// use ponjika::calendar::CalendarVariant;
+    /// use ponjika::date::JulianDate;
+    /// use ponjika::months::EnglishMonths;
+    /// use ponjika::season::BengaliSeasons;
+    /// let date = JulianDate::create_date(1, EnglishMonths::April, 2025).unwrap();
+    /// assert_eq!(date.parts_with_variant(CalendarVariant::Revised2019).season, BengaliSeasons::Grishmo);
+    /// assert_eq!(date.parts_with_variant(CalendarVariant::Traditional).season, BengaliSeasons::Bashonto);
+    /// ```
+    /// # Note
+    /// * The season is the season of the Bengali date of the variant on the same day
+    pub fn parts_with_variant(&self, variant: CalendarVariant) -> DateParts {
+        let (day, month, year) = self.get_date();
+        let rata_die = self.to_rata_die();
+
+        DateParts {
+            day,
+            month,
+            year,
+            month_name: match EnglishMonths::get_month(month) {
+                Ok(month) => Month::English(month),
+                Err(_) => Month::Unknown,
+            },
+            week_day: WeekDays::English(rata_die.get_english_week_day()),
+            season: season_of(rata_die, variant),
+        }
+    }
+}
+
+impl BengaliDate {
+    /// Get the typed parts of the selected date
+    /// # Returns
+    /// * `DateParts` - The numbers, the month, the week day and the season of the date
+    /// # Example
+    /// ```
+    /// use ponjika::date::BengaliDate;
+    /// use ponjika::days::{BengaliWeekDays, WeekDays};
+    /// use ponjika::months::{BengaliMonths, Month};
+    /// use ponjika::season::BengaliSeasons;
+    /// let date = BengaliDate::create_date(8, BengaliMonths::Falgun, 1430).unwrap();
+    /// let parts = date.parts();
+    /// assert_eq!((parts.day, parts.month, parts.year), (8, 11, 1430));
+    /// assert_eq!(parts.month_name, Month::Bengali(BengaliMonths::Falgun));
+    /// assert_eq!(parts.week_day, WeekDays::Bengali(BengaliWeekDays::Budhbar));
+    /// assert_eq!(parts.season, BengaliSeasons::Bashonto);
+    /// ```
+    pub fn parts(&self) -> DateParts {
+        let (day, month, year) = self.get_date();
+
+        DateParts {
+            day,
+            month,
+            year,
+            month_name: match BengaliMonths::get_month(month) {
+                Ok(month) => Month::Bengali(month),
+                Err(_) => Month::Unknown,
+            },
+            week_day: WeekDays::Bengali(self.to_rata_die().get_bengali_week_day()),
+            season: BengaliSeasons::from_month(month),
+        }
+    }
+}
+
+impl Date {
+    /// Get the typed parts of the selected date
+    /// # Returns
+    /// * `Result<DateParts, DateError>` - The numbers, the month, the week day and the season of the date
+    /// # Example
+    /// ```
+    /// use ponjika::date::{BengaliDate, Date};
+    /// use ponjika::months::BengaliMonths;
+    /// let date = Date::Bengali(BengaliDate::create_date(1, BengaliMonths::Baishakh, 1428).unwrap());
+    /// let parts = date.parts().unwrap();
+    /// assert_eq!((parts.day, parts.month, parts.year), (1, 1, 1428));
+    /// assert!(Date::Unknown.parts().is_err());
+    /// ```
+    /// # Note
+    /// * The function will use the `CalendarVariant::Revised2019` rules for the season of an English or Julian date
+    /// * The function will return `DateError::UnknownDate` if the date is `Date::Unknown`
+    pub fn parts(&self) -> Result<DateParts, DateError> {
+        match self {
+            Date::English(date) => Ok(date.parts()),
+            Date::Bengali(date) => Ok(date.parts()),
+            Date::Julian(date) => Ok(date.parts()),
+            Date::Unknown => Err(DateError::UnknownDate),
+        }
+    }
+}
@@ -0,0 +1,595 @@
+//! # Range: Runs of consecutive days
+//! The `range` module provides the `DateRange` struct, the days between two dates of the same calendar.
+//! The `RangeDate` trait is implemented by the dates that can bound a range.
+//! A range can be walked day by day, week by week or month by month.
+
+use crate::calendar::{self, CalendarVariant};
+use crate::date::{BengaliDate, DateError, EnglishDate, JulianDate};
+use crate::days::BengaliWeekDays;
+use crate::months::{BengaliMonths, EnglishMonths};
+use crate::rata_die::{self, RataDie};
+
+/// # `RangeDate`: The trait for the dates that can bound a `DateRange`.
+/// The trait is implemented by `EnglishDate`, `JulianDate` and `BengaliDate`.
+pub trait RangeDate: Copy + Ord {
+    /// Get the fixed day number of the date
+    fn to_rata_die(&self) -> RataDie;
+
+    /// Get the date of the same calendar on another day
+    fn with_rata_die(&self, rata_die: RataDie) -> Result<Self, DateError>;
+
+    /// Get the day of the month of the date
+    fn get_day_of_month(&self) -> u8;
+
+    /// Get the number of days of the month of the date
+    fn get_days_in_month(&self) -> u8;
+}
+
+impl RangeDate for EnglishDate {
+    fn to_rata_die(&self) -> RataDie {
+        EnglishDate::to_rata_die(self)
+    }
+
+    fn with_rata_die(&self, rata_die: RataDie) -> Result<Self, DateError> {
+        EnglishDate::from_rata_die(rata_die)
+    }
+
+    fn get_day_of_month(&self) -> u8 {
+        self.get_date().0
+    }
+
+    fn get_days_in_month(&self) -> u8 {
+        let (_, month, year) = self.get_date();
+        match EnglishMonths::get_month(month) {
+            Ok(month) => month.days_in(year),
+            Err(_) => 0,
+        }
+    }
+}
+
+impl RangeDate for JulianDate {
+    fn to_rata_die(&self) -> RataDie {
+        JulianDate::to_rata_die(self)
+    }
+
+    fn with_rata_die(&self, rata_die: RataDie) -> Result<Self, DateError> {
+        JulianDate::from_rata_die(rata_die)
+    }
+
+    fn get_day_of_month(&self) -> u8 {
+        self.get_date().0
+    }
+
+    fn get_days_in_month(&self) -> u8 {
+        let (_, month, year) = self.get_date();
+        rata_die::julian_days_in_month(month, year as i64)
+    }
+}
+
+impl RangeDate for BengaliDate {
+    fn to_rata_die(&self) -> RataDie {
+        BengaliDate::to_rata_die(self)
+    }
+
+    /// The date keeps the calendar variant of the selected date
+    fn with_rata_die(&self, rata_die: RataDie) -> Result<Self, DateError> {
+        BengaliDate::from_rata_die(rata_die, self.get_variant())
+    }
+
+    fn get_day_of_month(&self) -> u8 {
+        self.get_date().0
+    }
+
+    fn get_days_in_month(&self) -> u8 {
+        let (_, month, year) = self.get_date();
+        match BengaliMonths::get_month(month) {
+            Ok(month) => month.days_in_with_variant(year, self.get_variant()),
+            Err(_) => 0,
+        }
+    }
+}
+
+/// # `DateRange`: The days between two dates.
+/// The start is always part of the range, the end is part of it only if the range is inclusive.
+/// A range whose end is before its start is empty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DateRange<D> {
+    start: D,
+    end: D,
+    inclusive: bool,
+}
+
+impl<D: RangeDate> DateRange<D> {
+    /// Create a range that includes the end
+    /// # Arguments
+    /// * `start` - The first day
+    /// * `end` - The last day
+    /// # Returns
+    /// * `DateRange` - The range
+    /// # Example
+    /// ```
+    /// use ponjika::date::BengaliDate;
+    /// use ponjika::months::BengaliMonths;
+    /// use ponjika::range::DateRange;
+    /// let start = BengaliDate::create_date(1, BengaliMonths::Baishakh, 1431).unwrap();
+    /// let end = BengaliDate::create_date(7, BengaliMonths::Baishakh, 1431).unwrap();
+    /// assert_eq!(DateRange::inclusive(start, end).get_total_days(), 7);
+    /// ```
+    pub fn inclusive(start: D, end: D) -> Self {
+        DateRange {
+            start,
+            end,
+            inclusive: true,
+        }
+    }
+
+    /// Create a range that stops before the end
+    /// # Arguments
+    /// * `start` - The first day
+    /// * `end` - The day after the last day
+    /// # Returns
+    /// * `DateRange` - The range
+    /// # Example
+    /// ```
+    /// use ponjika::date::BengaliDate;
+    /// use ponjika::months::BengaliMonths;
+    /// use ponjika::range::DateRange;
+    /// let start = BengaliDate::create_date(1, BengaliMonths::Baishakh, 1431).unwrap();
+    /// let end = BengaliDate::create_date(7, BengaliMonths::Baishakh, 1431).unwrap();
+    /// assert_eq!(DateRange::exclusive(start, end).get_total_days(), 6);
+    /// ```
+    pub fn exclusive(start: D, end: D) -> Self {
+        DateRange {
+            start,
+            end,
+            inclusive: false,
+        }
+    }
+
+    /// Get the start of the selected range
+    /// # Returns
+    /// * `D` - The first day
+    pub fn get_start(&self) -> D {
+        self.start
+    }
+
+    /// Get the end of the selected range
+    /// # Returns
+    /// * `D` - The end, which is the last day only if the range is inclusive
+    pub fn get_end(&self) -> D {
+        self.end
+    }
+
+    /// Check whether the end is part of the selected range
+    /// # Returns
+    /// * `bool` - `true` if the range is inclusive
+    pub fn is_inclusive(&self) -> bool {
+        self.inclusive
+    }
+
+    fn first_day(&self) -> i64 {
+        self.start.to_rata_die().get_day_number()
+    }
+
+    fn last_day(&self) -> i64 {
+        self.end.to_rata_die().get_day_number() - (!self.inclusive) as i64
+    }
+
+    /// Check whether the selected range has no days
+    /// # Returns
+    /// * `bool` - `true` if the range is empty
+    /// # Example
+    /// ```
+    /// use ponjika::date::EnglishDate;
+    /// use ponjika::months::EnglishMonths;
+    /// use ponjika::range::DateRange;
+    /// let date = EnglishDate::create_date(14, EnglishMonths::April, 2024).unwrap();
+    /// assert!(DateRange::exclusive(date, date).is_empty());
+    /// assert!(!DateRange::inclusive(date, date).is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.last_day() < self.first_day()
+    }
+
+    /// Get the number of days of the selected range
+    /// # Returns
+    /// * `i64` - The number of days, 0 if the range is empty
+    pub fn get_total_days(&self) -> i64 {
+        (self.last_day() - self.first_day() + 1).max(0)
+    }
+
+    /// Check whether a day is in the selected range
+    /// # Arguments
+    /// * `date` - Any date, also of another calendar
+    /// # Returns
+    /// * `bool` - `true` if the range contains the day
+    /// # Example
+    /// ```
+    /// use ponjika::date::{BengaliDate, EnglishDate};
+    /// use ponjika::months::{BengaliMonths, EnglishMonths};
+    /// let baishakh = BengaliDate::days_of_month(BengaliMonths::Baishakh, 1431).unwrap();
+    /// let date = EnglishDate::create_date(14, EnglishMonths::May, 2024).unwrap();
+    /// assert!(baishakh.contains(&date));
+    /// let date = EnglishDate::create_date(15, EnglishMonths::May, 2024).unwrap();
+    /// assert!(!baishakh.contains(&date));
+    /// ```
+    pub fn contains<T: RangeDate>(&self, date: &T) -> bool {
+        let day = date.to_rata_die().get_day_number();
+        self.first_day() <= day && day <= self.last_day()
+    }
+
+    /// Get the days that are in both ranges
+    /// # Arguments
+    /// * `other` - &DateRange
+    /// # Returns
+    /// * `Option<DateRange>` - The common days, `None` if there are none
+    /// # Example
+    /// ```
+    /// use ponjika::date::BengaliDate;
+    /// use ponjika::months::BengaliMonths;
+    /// use ponjika::range::DateRange;
+    /// let first = BengaliDate::create_date(1, BengaliMonths::Baishakh, 1431).unwrap();
+    /// let second = BengaliDate::create_date(10, BengaliMonths::Baishakh, 1431).unwrap();
+    /// let third = BengaliDate::create_date(20, BengaliMonths::Baishakh, 1431).unwrap();
+    /// let common = DateRange::inclusive(first, third).intersection(&DateRange::inclusive(second, third));
+    /// assert_eq!(common, Some(DateRange::inclusive(second, third)));
+    /// assert_eq!(DateRange::exclusive(first, second).intersection(&DateRange::inclusive(second, third)), None);
+    /// ```
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let start = match self.first_day() >= other.first_day() {
+            true => self.start,
+            false => other.start,
+        };
+        let (end, inclusive) = match self.last_day() <= other.last_day() {
+            true => (self.end, self.inclusive),
+            false => (other.end, other.inclusive),
+        };
+
+        let range = DateRange {
+            start,
+            end,
+            inclusive,
+        };
+        match range.is_empty() {
+            true => None,
+            false => Some(range),
+        }
+    }
+
+    /// Check whether the ranges have a common day
+    /// # Arguments
+    /// * `other` - &DateRange
+    /// # Returns
+    /// * `bool` - `true` if the ranges overlap
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// Walk the selected range day by day
+    /// # Returns
+    /// * `DayIter` - The iterator over the days
+    /// # Example
+    /// ```
+    /// use ponjika::date::BengaliDate;
+    /// use ponjika::months::BengaliMonths;
+    /// let falgun = BengaliDate::days_of_month(BengaliMonths::Falgun, 1430).unwrap();
+    /// assert_eq!(falgun.iter_days().count(), 30);
+    /// assert_eq!(falgun.iter_days().last().unwrap().get_date(), (30, 11, 1430));
+    /// ```
+    pub fn iter_days(&self) -> DayIter<D> {
+        DayIter {
+            next: (!self.is_empty()).then_some(self.start),
+            last: self.last_day(),
+        }
+    }
+
+    /// Walk the selected range week by week
+    /// # Arguments
+    /// * `first_day` - BengaliWeekDays, the day a week starts on
+    /// # Returns
+    /// * `WeekIter` - The iterator over the weeks, the first and last ones can be partial
+    /// # Example
+    /// ```
+    /// use ponjika::date::BengaliDate;
+    /// use ponjika::days::BengaliWeekDays;
+    /// use ponjika::months::BengaliMonths;
+    /// // 1 Baishakh 1431 was a Sunday
+    /// let baishakh = BengaliDate::days_of_month(BengaliMonths::Baishakh, 1431).unwrap();
+    /// let weeks: Vec<_> = baishakh.weeks(BengaliWeekDays::Shonibar).unwrap().collect();
+    /// assert_eq!(weeks.len(), 5);
+    /// assert_eq!(weeks[0].get_total_days(), 6);
+    /// assert_eq!(weeks[4].get_total_days(), 4);
+    /// ```
+    /// # Note
+    /// * The function will return `DateError::WrongWeekDay` if the week day is `BengaliWeekDays::UnImplemented`
+    pub fn weeks(&self, first_day: BengaliWeekDays) -> Result<WeekIter<D>, DateError> {
+        let first_day = match first_day.get_day_number() {
+            Ok(first_day) => first_day,
+            Err(err) => return Err(DateError::WrongWeekDay(err)),
+        };
+
+        Ok(WeekIter {
+            days: self.iter_days(),
+            first_day,
+        })
+    }
+
+    /// Walk the selected range month by month
+    /// # Returns
+    /// * `MonthIter` - The iterator over the months, the first and last ones can be partial
+    /// # Example
+    /// ```
+    /// use ponjika::date::BengaliDate;
+    /// use ponjika::range::DateRange;
+    /// let year = BengaliDate::days_of_year(1431).unwrap();
+    /// let lengths: Vec<i64> = year.months().map(|month| month.get_total_days()).collect();
+    /// assert_eq!(lengths, vec![31, 31, 31, 31, 31, 31, 30, 30, 30, 30, 29, 30]);
+    /// ```
+    pub fn months(&self) -> MonthIter<D> {
+        MonthIter {
+            days: self.iter_days(),
+        }
+    }
+}
+
+impl<D: RangeDate> IntoIterator for DateRange<D> {
+    type Item = D;
+    type IntoIter = DayIter<D>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_days()
+    }
+}
+
+/// # `DayIter`: The iterator over consecutive days.
+#[derive(Debug, Clone)]
+pub struct DayIter<D> {
+    next: Option<D>,
+    last: i64,
+}
+
+impl<D: RangeDate> DayIter<D> {
+    /// Take the next day and the last day of the chunk that ends `length` days later at the latest
+    /// # Note
+    /// * The days are between the bounds of the range, which were checked when the range was built,
+    ///   or end with the last supported day of the calendar, so a failed conversion is a bug and panics
+    fn next_chunk(&mut self, length: i64) -> Option<DateRange<D>> {
+        let start = self.next?;
+        let end_day = (start.to_rata_die().get_day_number() + length - 1).min(self.last);
+        let end = start
+            .with_rata_die(RataDie::new(end_day))
+            .expect("the days of a range are inside its checked bounds");
+
+        self.next = match end_day < self.last {
+            true => Some(
+                start
+                    .with_rata_die(RataDie::new(end_day + 1))
+                    .expect("the days of a range are inside its checked bounds"),
+            ),
+            false => None,
+        };
+        Some(DateRange::inclusive(start, end))
+    }
+}
+
+impl<D: RangeDate> Iterator for DayIter<D> {
+    type Item = D;
+
+    fn next(&mut self) -> Option<D> {
+        self.next_chunk(1).map(|range| range.get_start())
+    }
+}
+
+/// # `WeekIter`: The iterator over the weeks of a range.
+#[derive(Debug, Clone)]
+pub struct WeekIter<D> {
+    days: DayIter<D>,
+    first_day: i64,
+}
+
+impl<D: RangeDate> Iterator for WeekIter<D> {
+    type Item = DateRange<D>;
+
+    fn next(&mut self) -> Option<DateRange<D>> {
+        // the week runs until the day before the next first day of the week
+        let week_day = self.days.next?.to_rata_die().get_day_number().rem_euclid(7);
+        self.days
+            .next_chunk(7 - (week_day - self.first_day).rem_euclid(7))
+    }
+}
+
+/// # `MonthIter`: The iterator over the months of a range.
+#[derive(Debug, Clone)]
+pub struct MonthIter<D> {
+    days: DayIter<D>,
+}
+
+impl<D: RangeDate> Iterator for MonthIter<D> {
+    type Item = DateRange<D>;
+
+    fn next(&mut self) -> Option<DateRange<D>> {
+        let start = self.days.next?;
+        let length = start.get_days_in_month() as i64 - start.get_day_of_month() as i64 + 1;
+        self.days.next_chunk(length)
+    }
+}
+
+impl EnglishDate {
+    /// Walk the days from the selected date on
+    /// # Returns
+    /// * `DayIter` - The iterator over the days, ending with the last supported day
+    /// # Example
+    /// ```
+    /// use ponjika::date::EnglishDate;
+    /// use ponjika::months::EnglishMonths;
+    /// let date = EnglishDate::create_date(28, EnglishMonths::February, 2024).unwrap();
+    /// let days: Vec<_> = date.iter_days().take(3).map(|date| date.get_date()).collect();
+    /// assert_eq!(days, vec![(28, 2, 2024), (29, 2, 2024), (1, 3, 2024)]);
+    /// ```
+    pub fn iter_days(&self) -> DayIter<EnglishDate> {
+        DayIter {
+            next: Some(*self),
+            last: *rata_die::gregorian_days().end(),
+        }
+    }
+
+    /// Get the days of an English month
+    /// # Arguments
+    /// * `month` - EnglishMonths
+    /// * `year` - u16
+    /// # Returns
+    /// * `DateRange` - The days of the month
+    /// # Example
+    /// ```
+    /// use ponjika::date::EnglishDate;
+    /// use ponjika::months::EnglishMonths;
+    /// let february = EnglishDate::days_of_month(EnglishMonths::February, 2024).unwrap();
+    /// assert_eq!(february.get_total_days(), 29);
+    /// ```
+    /// # Note
+    /// * The function will return `DateError::OutOfRange` if the year is out of the supported range
+    pub fn days_of_month(
+        month: EnglishMonths,
+        year: u16,
+    ) -> Result<DateRange<EnglishDate>, DateError> {
+        Ok(DateRange::inclusive(
+            EnglishDate::create_date(1, month, year)?,
+            EnglishDate::create_date(month.days_in(year), month, year)?,
+        ))
+    }
+
+    /// Get the days of an English year
+    /// # Arguments
+    /// * `year` - u16
+    /// # Returns
+    /// * `DateRange` - The days of the year
+    /// # Example
+    /// ```
+    /// use ponjika::date::EnglishDate;
+    /// assert_eq!(EnglishDate::days_of_year(2024).unwrap().get_total_days(), 366);
+    /// ```
+    /// # Note
+    /// * The function will return `DateError::OutOfRange` if the year is out of the supported range
+    pub fn days_of_year(year: u16) -> Result<DateRange<EnglishDate>, DateError> {
+        Ok(DateRange::inclusive(
+            EnglishDate::create_date(1, EnglishMonths::January, year)?,
+            EnglishDate::create_date(31, EnglishMonths::December, year)?,
+        ))
+    }
+}
+
+impl BengaliDate {
+    /// Walk the days from the selected date on
+    /// # Returns
+    /// * `DayIter` - The iterator over the days, ending with the last supported day
+    /// # Example
+    /// ```
+    /// use ponjika::date::BengaliDate;
+    /// use ponjika::months::BengaliMonths;
+    /// let date = BengaliDate::create_date(30, BengaliMonths::Chaitra, 1430).unwrap();
+    /// let days: Vec<_> = date.iter_days().take(2).map(|date| date.get_date()).collect();
+    /// assert_eq!(days, vec![(30, 12, 1430), (1, 1, 1431)]);
+    /// ```
+    /// # Note
+    /// * The days keep the calendar variant of the selected date
+    pub fn iter_days(&self) -> DayIter<BengaliDate> {
+        DayIter {
+            next: Some(*self),
+            last: *calendar::bengali_days(self.get_variant()).end(),
+        }
+    }
+
+    /// Get the days of a Bengali month
+    /// # Arguments
+    /// * `month` - BengaliMonths
+    /// * `year` - u16
+    /// # Returns
+    /// * `DateRange` - The days of the month
+    /// # Example
+    /// ```
+    /// use ponjika::date::BengaliDate;
+    /// use ponjika::months::BengaliMonths;
+    /// let ashwin = BengaliDate::days_of_month(BengaliMonths::Ashwin, 1431).unwrap();
+    /// assert_eq!(ashwin.get_total_days(), 31);
+    /// ```
+    /// # Note
+    /// * The function will use the `CalendarVariant::Revised2019` rules
+    /// * The function will return `DateError::OutOfRange` if the year is out of the supported range
+    pub fn days_of_month(
+        month: BengaliMonths,
+        year: u16,
+    ) -> Result<DateRange<BengaliDate>, DateError> {
+        Self::days_of_month_with_variant(month, year, CalendarVariant::default())
+    }
+
+    /// Get the days of a Bengali month in a calendar variant
+    /// # Arguments
+    /// * `month` - BengaliMonths
+    /// * `year` - u16
+    /// * `variant` - CalendarVariant
+    /// # Returns
+    /// * `DateRange` - The days of the month
+    /// # Example
+    /// ```
+    /// use ponjika::calendar::CalendarVariant;
+    /// use ponjika::date::BengaliDate;
+    /// use ponjika::months::BengaliMonths;
+    /// let shrawan = BengaliDate::days_of_month_with_variant(BengaliMonths::Shrawan, 1431, CalendarVariant::Traditional);
+    /// assert_eq!(shrawan.unwrap().get_total_days(), 32);
+    /// ```
+    /// # Note
+    /// * The function will return `DateError::OutOfRange` if the year is out of the supported range
+    pub fn days_of_month_with_variant(
+        month: BengaliMonths,
+        year: u16,
+        variant: CalendarVariant,
+    ) -> Result<DateRange<BengaliDate>, DateError> {
+        let first = BengaliDate::create_date_with_variant(1, month, year, variant)?;
+        let last = first.with_rata_die(RataDie::new(
+            first.to_rata_die().get_day_number() + first.get_days_in_month() as i64 - 1,
+        ))?;
+        Ok(DateRange::inclusive(first, last))
+    }
+
+    /// Get the days of a Bengali year
+    /// # Arguments
+    /// * `year` - u16
+    /// # Returns
+    /// * `DateRange` - The days of the year
+    /// # Example
+    /// ```
+    /// use ponjika::date::BengaliDate;
+    /// assert_eq!(BengaliDate::days_of_year(1430).unwrap().get_total_days(), 366);
+    /// ```
+    /// # Note
+    /// * The function will use the `CalendarVariant::Revised2019` rules
+    /// * The function will return `DateError::OutOfRange` if the year is out of the supported range
+    pub fn days_of_year(year: u16) -> Result<DateRange<BengaliDate>, DateError> {
+        Self::days_of_year_with_variant(year, CalendarVariant::default())
+    }
+
+    /// Get the days of a Bengali year in a calendar variant
+    /// # Arguments
+    /// * `year` - u16
+    /// * `variant` - CalendarVariant
+    /// # Returns
+    /// * `DateRange` - The days of the year
+    /// # Example
+    /// ```
+    /// use ponjika::calendar::CalendarVariant;
+    /// use ponjika::date::BengaliDate;
+    /// let year = BengaliDate::days_of_year_with_variant(1431, CalendarVariant::Traditional).unwrap();
+    /// assert_eq!(year.get_total_days(), 366);
+    /// ```
+    /// # Note
+    /// * The function will return `DateError::OutOfRange` if the year is out of the supported range
+    pub fn days_of_year_with_variant(
+        year: u16,
+        variant: CalendarVariant,
+    ) -> Result<DateRange<BengaliDate>, DateError> {
+        let first =
+            BengaliDate::create_date_with_variant(1, BengaliMonths::Baishakh, year, variant)?;
+        let last = Self::days_of_month_with_variant(BengaliMonths::Chaitra, year, variant)?;
+        Ok(DateRange::inclusive(first, last.get_end()))
+    }
+}
@@ -0,0 +1,237 @@
+//! # Rata Die: The fixed day number shared by the calendars
+//! The `rata_die` module provides the `RataDie` struct, a fixed count of days.
+//! Day 1 is 1st January of 1 CE in the proleptic Gregorian calendar.
+//! Every date converts into a day number and out of it, so a conversion between two calendars
+//! is always "date → day number → other calendar".
+
+use std::ops::RangeInclusive;
+
+use crate::calendar::{is_julian_leap_year, is_leap_year};
+use crate::date::{DateError, DateField};
+use crate::days::{BengaliWeekDays, EnglishWeekDays};
+
+/// The fixed day number of 1st January of 1 CE in the Julian calendar
+const JULIAN_EPOCH: i64 = -1;
+
+/// # `RataDie`: The struct for the fixed day number.
+/// The struct is used to represent a day independently of any calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RataDie(i64);
+
+impl RataDie {
+    /// Create a fixed day number
+    /// # Arguments
+    /// * `day_number` - i64
+    /// # Returns
+    /// * `RataDie` - The fixed day number
+    /// # Example
+    /// ```
+    /// use ponjika::rata_die::RataDie;
+    /// let day = RataDie::new(738_990);
+    /// assert_eq!(day.get_day_number(), 738_990);
+    /// ```
+    pub fn new(day_number: i64) -> Self {
+        RataDie(day_number)
+    }
+
+    /// Get the number of the selected day
+    /// # Returns
+    /// * `i64` - The day number, where 1st January of 1 CE is 1
+    /// # Example
+    /// ```
+    /// use ponjika::date::EnglishDate;
+    /// use ponjika::months::EnglishMonths;
+    /// let date = EnglishDate::create_date(1, EnglishMonths::January, 2021).unwrap();
+    /// assert_eq!(date.to_rata_die().get_day_number(), 737_791);
+    /// ```
+    pub fn get_day_number(&self) -> i64 {
+        self.0
+    }
+
+    /// Get the English week day of the selected day
+    /// # Returns
+    /// * `EnglishWeekDays` - The English week day
+    /// # Example
+    /// ```
+    /// use ponjika::days::EnglishWeekDays;
+    /// use ponjika::rata_die::RataDie;
+    /// assert_eq!(RataDie::new(1).get_english_week_day(), EnglishWeekDays::Monday);
+    /// ```
+    pub fn get_english_week_day(&self) -> EnglishWeekDays {
+        match self.0.rem_euclid(7) {
+            0 => EnglishWeekDays::Sunday,
+            1 => EnglishWeekDays::Monday,
+            2 => EnglishWeekDays::Tuesday,
+            3 => EnglishWeekDays::Wednesday,
+            4 => EnglishWeekDays::Thursday,
+            5 => EnglishWeekDays::Friday,
+            _ => EnglishWeekDays::Saturday,
+        }
+    }
+
+    /// Get the Bengali week day of the selected day
+    /// # Returns
+    /// * `BengaliWeekDays` - The Bengali week day
+    /// # Example
+    /// ```
+    /// use ponjika::days::BengaliWeekDays;
+    /// use ponjika::rata_die::RataDie;
+    /// assert_eq!(RataDie::new(1).get_bengali_week_day(), BengaliWeekDays::Sombar);
+    /// ```
+    pub fn get_bengali_week_day(&self) -> BengaliWeekDays {
+        self.get_english_week_day().map_to_bengali()
+    }
+
+    /// Check that the selected day is one of the supported days of a calendar
+    /// # Arguments
+    /// * `days` - RangeInclusive<i64>, the fixed day numbers of the first and the last supported day
+    /// # Returns
+    /// * `Result<(), DateError>` - Nothing if the day is inside the range
+    /// # Note
+    /// * The function will return `DateError::OutOfRange` with the day number if the day is outside of the range
+    /// * The check keeps the calendar math away from the day numbers that would overflow it
+    pub(crate) fn check_range(&self, days: RangeInclusive<i64>) -> Result<(), DateError> {
+        match days.contains(&self.0) {
+            true => Ok(()),
+            false => Err(DateError::out_of_range(
+                DateField::DayNumber,
+                self.0,
+                *days.start(),
+                *days.end(),
+            )),
+        }
+    }
+}
+
+/// Get the fixed day numbers of the supported Gregorian days
+/// # Returns
+/// * `RangeInclusive<i64>` - The days from 1st January 593 to 31st December 9999
+pub(crate) fn gregorian_days() -> RangeInclusive<i64> {
+    fixed_from_gregorian(1, 1, 593)..=fixed_from_gregorian(31, 12, 9999)
+}
+
+/// Get the fixed day number of a Gregorian date
+/// # Arguments
+/// * `day` - u8
+/// * `month` - u8
+/// * `year` - i64
+/// # Returns
+/// * `i64` - The fixed day number
+pub(crate) fn fixed_from_gregorian(day: u8, month: u8, year: i64) -> i64 {
+    let previous = year - 1;
+    let month_adjustment = if month <= 2 {
+        0
+    } else if is_leap_year(year) {
+        -1
+    } else {
+        -2
+    };
+
+    365 * previous + previous.div_euclid(4) - previous.div_euclid(100)
+        + previous.div_euclid(400)
+        + (367 * month as i64 - 362).div_euclid(12)
+        + month_adjustment
+        + day as i64
+}
+
+/// Get the Gregorian date of a fixed day number
+/// # Arguments
+/// * `fixed` - i64
+/// # Returns
+/// * `(u8, u8, i64)` - The day, month, and year
+pub(crate) fn gregorian_from_fixed(fixed: i64) -> (u8, u8, i64) {
+    // count the whole 400, 100, 4 and 1 year cycles before the day
+    let d0 = fixed - 1;
+    let n400 = d0.div_euclid(146_097);
+    let d1 = d0.rem_euclid(146_097);
+    let n100 = d1 / 36_524;
+    let d2 = d1 % 36_524;
+    let n4 = d2 / 1_461;
+    let d3 = d2 % 1_461;
+    let n1 = d3 / 365;
+    let year = 400 * n400 + 100 * n100 + 4 * n4 + n1;
+    let year = if n100 == 4 || n1 == 4 { year } else { year + 1 };
+
+    let prior_days = fixed - fixed_from_gregorian(1, 1, year);
+    let correction = if fixed < fixed_from_gregorian(1, 3, year) {
+        0
+    } else if is_leap_year(year) {
+        1
+    } else {
+        2
+    };
+    let month = (12 * (prior_days + correction) + 373) / 367;
+    let day = fixed - fixed_from_gregorian(1, month as u8, year) + 1;
+
+    (day as u8, month as u8, year)
+}
+
+/// Get the fixed day number of a Julian date
+/// # Arguments
+/// * `day` - u8
+/// * `month` - u8
+/// * `year` - i64
+/// # Returns
+/// * `i64` - The fixed day number
+/// # Note
+/// * 1st January of 1 CE in the Julian calendar is 30th December of 0 in the proleptic Gregorian calendar
+pub(crate) fn fixed_from_julian(day: u8, month: u8, year: i64) -> i64 {
+    let previous = year - 1;
+    let month_adjustment = if month <= 2 {
+        0
+    } else if is_julian_leap_year(year) {
+        -1
+    } else {
+        -2
+    };
+
+    JULIAN_EPOCH - 1
+        + 365 * previous
+        + previous.div_euclid(4)
+        + (367 * month as i64 - 362).div_euclid(12)
+        + month_adjustment
+        + day as i64
+}
+
+/// Get the fixed day numbers of the supported Julian days
+/// # Returns
+/// * `RangeInclusive<i64>` - The days from 1st January 593 to 31st December 9999 of the Julian calendar
+pub(crate) fn julian_days() -> RangeInclusive<i64> {
+    fixed_from_julian(1, 1, 593)..=fixed_from_julian(31, 12, 9999)
+}
+
+/// Get the Julian date of a fixed day number
+/// # Arguments
+/// * `fixed` - i64
+/// # Returns
+/// * `(u8, u8, i64)` - The day, month, and year
+pub(crate) fn julian_from_fixed(fixed: i64) -> (u8, u8, i64) {
+    let year = (4 * (fixed - JULIAN_EPOCH) + 1464).div_euclid(1461);
+
+    let prior_days = fixed - fixed_from_julian(1, 1, year);
+    let correction = if fixed < fixed_from_julian(1, 3, year) {
+        0
+    } else if is_julian_leap_year(year) {
+        1
+    } else {
+        2
+    };
+    let month = (12 * (prior_days + correction) + 373) / 367;
+    let day = fixed - fixed_from_julian(1, month as u8, year) + 1;
+
+    (day as u8, month as u8, year)
+}
+
+/// Get the number of days of a Julian month
+/// # Arguments
+/// * `month` - u8
+/// * `year` - i64
+/// # Returns
+/// * `u8` - The number of days
+pub(crate) fn julian_days_in_month(month: u8, year: i64) -> u8 {
+    let (next_month, next_year) = match month {
+        12 => (1, year + 1),
+        _ => (month + 1, year),
+    };
+    (fixed_from_julian(1, next_month, next_year) - fixed_from_julian(1, month, year)) as u8
+}
@@ -1,3 +1,12 @@
+//! # Season: The six seasons of the Bengali year
+//! The `season` module provides the six seasons (ঋতু) of the Bengali year.
+//! Each season is two Bengali months long, starting with Grishmo in Baishakh and Jestha.
+
+use std::fmt;
+
+/// # `BengaliSeasons`: The enum for the Bengali seasons.
+/// The enum variants are the Bengali seasons.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum BengaliSeasons {
     Grishmo,
     Borsha,
@@ -8,6 +17,14 @@ pub enum BengaliSeasons {
 }
 
 impl BengaliSeasons {
+    /// Get the Bengali name of the selected season
+    /// # Returns
+    /// * `&str` - The name of the season
+    /// # Example
+    /// ```
+    /// use ponjika::season::BengaliSeasons;
+    /// assert_eq!(BengaliSeasons::Borsha.as_str(), "বর্ষা");
+    /// ```
     pub fn as_str(&self) -> &str {
         match self {
             BengaliSeasons::Grishmo => "গ্রীষ্ম",
@@ -19,6 +36,19 @@ impl BengaliSeasons {
         }
     }
 
+    /// Get the season of a Bengali month
+    /// # Arguments
+    /// * `month` - u8, the Bengali month from 1 (Baishakh) to 12 (Chaitra)
+    /// # Returns
+    /// * `BengaliSeasons` - The season of the month
+    /// # Example
+    /// ```
+    /// use ponjika::season::BengaliSeasons;
+    /// assert_eq!(BengaliSeasons::from_month(1), BengaliSeasons::Grishmo);
+    /// assert_eq!(BengaliSeasons::from_month(12), BengaliSeasons::Bashonto);
+    /// ```
+    /// # Note
+    /// * The function will return `BengaliSeasons::Sheet` if the month is not between 1 and 12
     pub fn from_month(month: u8) -> BengaliSeasons {
         match month {
             1 | 2 => BengaliSeasons::Grishmo,
@@ -31,3 +61,18 @@ impl BengaliSeasons {
         }
     }
 }
+
+impl fmt::Display for BengaliSeasons {
+    /// Display the Bengali season name
+    /// # Returns
+    /// * `fmt::Result` - The season name
+    /// # Example
+    /// ```
+    /// use ponjika::season::BengaliSeasons;
+    /// let season = BengaliSeasons::Sharat;
+    /// println!("{}", season);
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
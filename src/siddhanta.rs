@@ -0,0 +1,160 @@
+//! # Siddhanta: The sidereal solar months of the traditional calendar
+//! The `siddhanta` module computes the months of the traditional Bengali calendar used in West Bengal.
+//! The sun's sidereal longitude follows the Surya Siddhanta, and a month starts on the day after
+//! the sun enters its zodiac sign (sankranti).
+//! The days are counted as fixed day numbers where 1 is 1st January of 1 CE in the proleptic Gregorian calendar.
+
+/// The fixed day number of the Kali Yuga epoch, midnight at Ujjain on 18th February 3102 BCE (Julian)
+const KALI_EPOCH: f64 = -1132959.0;
+
+/// The number of days in a sidereal year
+const SIDEREAL_YEAR: f64 = 365.0 + 279457.0 / 1080000.0;
+
+/// The number of days between the creation and the Kali Yuga epoch
+const DAYS_SINCE_CREATION: i128 = 1955880000 * 365 + 1811 * 279457;
+
+/// The anomalistic year is `ANOMALISTIC_DAYS / ANOMALISTIC_REVOLUTIONS` days
+const ANOMALISTIC_DAYS: i128 = 1577917828000;
+const ANOMALISTIC_REVOLUTIONS: i128 = 4320000000 - 387;
+
+/// The number of Kali years elapsed at the start of the Bengali year 0
+const KALI_YEAR_OFFSET: f64 = 3694.0;
+
+/// The fraction of a day between the Ujjain meridian (75°46'6" E) and Indian Standard Time (82°30' E)
+const UJJAIN_TO_IST: f64 = (82.5 - (75.0 + 46.0 / 60.0 + 6.0 / 3600.0)) / 360.0;
+
+/// The tabulated sine of the Surya Siddhanta for multiples of 225 arc minutes
+fn sine_table(entry: i32) -> f64 {
+    let exact = 3438.0 * (entry as f64 * 3.75).to_radians().sin();
+    let error = 0.215 * exact.signum() * (exact.abs() - 1716.0).signum();
+    (exact + error).round() / 3438.0
+}
+
+/// The sine of an angle in degrees, interpolated from the table
+fn sine(theta: f64) -> f64 {
+    let entry = theta / 3.75;
+    let fraction = entry.rem_euclid(1.0);
+    fraction * sine_table(entry.ceil() as i32) + (1.0 - fraction) * sine_table(entry.floor() as i32)
+}
+
+/// The inverse of the tabulated sine in degrees
+fn arcsin(amplitude: f64) -> f64 {
+    if amplitude < 0.0 {
+        return -arcsin(-amplitude);
+    }
+
+    let mut position = 0;
+    while amplitude > sine_table(position) {
+        position += 1;
+    }
+
+    let below = sine_table(position - 1);
+    3.75 * ((position - 1) as f64 + (amplitude - below) / (sine_table(position) - below))
+}
+
+/// The mean anomaly of the sun in degrees at a moment in Ujjain time
+fn mean_anomaly(moment: f64) -> f64 {
+    // The anomalistic year does not divide the days since the creation, so the whole days are
+    // reduced exactly before the fraction of the day is added
+    let elapsed = moment - KALI_EPOCH;
+    let days = elapsed.floor();
+    let whole = (DAYS_SINCE_CREATION + days as i128) * ANOMALISTIC_REVOLUTIONS % ANOMALISTIC_DAYS;
+    let revolutions = (whole as f64 + (elapsed - days) * ANOMALISTIC_REVOLUTIONS as f64)
+        / ANOMALISTIC_DAYS as f64;
+    360.0 * revolutions.rem_euclid(1.0)
+}
+
+/// The sidereal longitude of the sun in degrees at a moment in Ujjain time
+fn solar_longitude(moment: f64) -> f64 {
+    let mean = 360.0 * ((moment - KALI_EPOCH) / SIDEREAL_YEAR).rem_euclid(1.0);
+    let offset = sine(mean_anomaly(moment));
+    let contraction = offset.abs() * (1.0 / 42.0) * (14.0 / 360.0);
+    let equation = arcsin(offset * (14.0 / 360.0 - contraction));
+    (mean - equation).rem_euclid(360.0)
+}
+
+/// The moment in Indian Standard Time when the sun enters the sign of a Bengali month
+fn sankranti(year: i64, month: u8) -> f64 {
+    let longitude = 30.0 * (month - 1) as f64;
+    let estimate =
+        KALI_EPOCH + (year as f64 + KALI_YEAR_OFFSET + (month - 1) as f64 / 12.0) * SIDEREAL_YEAR;
+
+    // The true sun is at most a few days away from the mean sun
+    let (mut low, mut high) = (estimate - 10.0, estimate + 10.0);
+    while high - low > 1e-6 {
+        let middle = (low + high) / 2.0;
+        if (solar_longitude(middle) - longitude).rem_euclid(360.0) < 180.0 {
+            high = middle;
+        } else {
+            low = middle;
+        }
+    }
+
+    high + UJJAIN_TO_IST
+}
+
+/// Get the fixed day number of the first day of a Bengali month
+/// # Arguments
+/// * `year` - i64
+/// * `month` - u8
+/// # Returns
+/// * `i64` - The fixed day number
+/// # Note
+/// * The month starts on the day after the sankranti
+pub(crate) fn month_start(year: i64, month: u8) -> i64 {
+    sankranti(year, month).floor() as i64 + 1
+}
+
+/// Get the number of days in a Bengali month
+/// # Arguments
+/// * `year` - i64
+/// * `month` - u8
+/// # Returns
+/// * `u8` - The number of days, between 29 and 32
+pub(crate) fn days_in_month(year: i64, month: u8) -> u8 {
+    let next = if month == 12 {
+        month_start(year + 1, 1)
+    } else {
+        month_start(year, month + 1)
+    };
+    (next - month_start(year, month)) as u8
+}
+
+/// Get the Bengali date of a fixed day number
+/// # Arguments
+/// * `fixed` - i64
+/// # Returns
+/// * `(u8, u8, i64)` - The day, month, and year
+pub(crate) fn from_fixed(fixed: i64) -> (u8, u8, i64) {
+    let mut year =
+        ((fixed as f64 - KALI_EPOCH) / SIDEREAL_YEAR).floor() as i64 - KALI_YEAR_OFFSET as i64;
+    if fixed < month_start(year, 1) {
+        year -= 1;
+    } else if fixed >= month_start(year + 1, 1) {
+        year += 1;
+    }
+
+    // the month of a day is the sign the sun is in at the start of the day
+    let longitude = solar_longitude(fixed as f64 - UJJAIN_TO_IST);
+    let mut month = (longitude / 30.0).floor().clamp(0.0, 11.0) as u8 + 1;
+    while month > 1 && fixed < month_start(year, month) {
+        month -= 1;
+    }
+    while month < 12 && fixed >= month_start(year, month + 1) {
+        month += 1;
+    }
+
+    let day = fixed - month_start(year, month) + 1;
+    (day as u8, month, year)
+}
+
+/// Get the fixed day number of a Bengali date
+/// # Arguments
+/// * `day` - u8
+/// * `month` - u8
+/// * `year` - i64
+/// # Returns
+/// * `i64` - The fixed day number
+pub(crate) fn to_fixed(day: u8, month: u8, year: i64) -> i64 {
+    month_start(year, month) + day as i64 - 1
+}
@@ -0,0 +1,48 @@
+//! # Sun: The time of sunrise
+//! The `sun` module computes the moment of sunrise with the sunrise equation.
+//! The moments are counted as fixed day numbers in UTC, where 1 is midnight at the start of 1st January of 1 CE
+//! in the proleptic Gregorian calendar. The result is accurate to a minute or two.
+
+/// The Julian day number at midnight at the start of the fixed day 0
+const JULIAN_DAY_OFFSET: f64 = 1_721_424.5;
+
+/// The Julian day number of noon on 1st January 2000
+const J2000: f64 = 2_451_545.0;
+
+/// The altitude of the sun's center at sunrise, corrected for refraction and the radius of the sun
+const SUNRISE_ALTITUDE: f64 = -0.833;
+
+/// The obliquity of the ecliptic in degrees
+const OBLIQUITY: f64 = 23.4397;
+
+/// Get the moment of sunrise on a day at a location
+/// # Arguments
+/// * `fixed` - i64, the local day
+/// * `latitude` - f64, degrees north
+/// * `longitude` - f64, degrees east
+/// # Returns
+/// * `Option<f64>` - The moment of sunrise in UTC
+/// # Note
+/// * The function will return `None` if the sun does not rise or does not set on the day
+pub(crate) fn sunrise(fixed: i64, latitude: f64, longitude: f64) -> Option<f64> {
+    // the mean solar noon at the location
+    let days = fixed as f64 + JULIAN_DAY_OFFSET + 0.5 - J2000 - longitude / 360.0;
+
+    let anomaly = (357.5291 + 0.98560028 * days).rem_euclid(360.0);
+    let center = 1.9148 * anomaly.to_radians().sin()
+        + 0.02 * (2.0 * anomaly).to_radians().sin()
+        + 0.0003 * (3.0 * anomaly).to_radians().sin();
+    let ecliptic_longitude = (anomaly + center + 180.0 + 102.9372).rem_euclid(360.0);
+    let transit = J2000 + days + 0.0053 * anomaly.to_radians().sin()
+        - 0.0069 * (2.0 * ecliptic_longitude).to_radians().sin();
+
+    let declination = (ecliptic_longitude.to_radians().sin() * OBLIQUITY.to_radians().sin()).asin();
+    let latitude = latitude.to_radians();
+    let hour_angle = (SUNRISE_ALTITUDE.to_radians().sin() - latitude.sin() * declination.sin())
+        / (latitude.cos() * declination.cos());
+    if !(-1.0..=1.0).contains(&hour_angle) {
+        return None;
+    }
+
+    Some(transit - hour_angle.acos().to_degrees() / 360.0 - JULIAN_DAY_OFFSET)
+}
@@ -0,0 +1,268 @@
+//! # Words: Writing numbers and dates in Bengali words
+//! The `words` module writes numbers in Bengali words with the Indian grouping of শো (hundred),
+//! হাজার (thousand), লক্ষ (hundred thousand) and কোটি (ten million), and the days of the month in
+//! the traditional forms used in dates (পয়লা, একুশে, পঁচিশে).
+
+use crate::date::{BengaliDate, Date, DateError, DateField, EnglishDate, JulianDate};
+
+/// The pattern of the dates written in words, for example `পঁচিশে বৈশাখ, এক হাজার চারশো একত্রিশ বঙ্গাব্দ`
+pub const LONG_WORDS: &str = "%Wd %OB, %WY %OE";
+
+/// The words for the numbers from 0 to 99
+const NUMBERS: [&str; 100] = [
+    "শূন্য",
+    "এক",
+    "দুই",
+    "তিন",
+    "চার",
+    "পাঁচ",
+    "ছয়",
+    "সাত",
+    "আট",
+    "নয়",
+    "দশ",
+    "এগারো",
+    "বারো",
+    "তেরো",
+    "চোদ্দো",
+    "পনেরো",
+    "ষোলো",
+    "সতেরো",
+    "আঠারো",
+    "উনিশ",
+    "বিশ",
+    "একুশ",
+    "বাইশ",
+    "তেইশ",
+    "চব্বিশ",
+    "পঁচিশ",
+    "ছাব্বিশ",
+    "সাতাশ",
+    "আটাশ",
+    "ঊনত্রিশ",
+    "ত্রিশ",
+    "একত্রিশ",
+    "বত্রিশ",
+    "তেত্রিশ",
+    "চৌত্রিশ",
+    "পঁয়ত্রিশ",
+    "ছত্রিশ",
+    "সাঁইত্রিশ",
+    "আটত্রিশ",
+    "ঊনচল্লিশ",
+    "চল্লিশ",
+    "একচল্লিশ",
+    "বিয়াল্লিশ",
+    "তেতাল্লিশ",
+    "চুয়াল্লিশ",
+    "পঁয়তাল্লিশ",
+    "ছেচল্লিশ",
+    "সাতচল্লিশ",
+    "আটচল্লিশ",
+    "ঊনপঞ্চাশ",
+    "পঞ্চাশ",
+    "একান্ন",
+    "বাহান্ন",
+    "তিপ্পান্ন",
+    "চুয়ান্ন",
+    "পঞ্চান্ন",
+    "ছাপ্পান্ন",
+    "সাতান্ন",
+    "আটান্ন",
+    "ঊনষাট",
+    "ষাট",
+    "একষট্টি",
+    "বাষট্টি",
+    "তেষট্টি",
+    "চৌষট্টি",
+    "পঁয়ষট্টি",
+    "ছেষট্টি",
+    "সাতষট্টি",
+    "আটষট্টি",
+    "ঊনসত্তর",
+    "সত্তর",
+    "একাত্তর",
+    "বাহাত্তর",
+    "তিয়াত্তর",
+    "চুয়াত্তর",
+    "পঁচাত্তর",
+    "ছিয়াত্তর",
+    "সাতাত্তর",
+    "আটাত্তর",
+    "ঊনআশি",
+    "আশি",
+    "একাশি",
+    "বিরাশি",
+    "তিরাশি",
+    "চুরাশি",
+    "পঁচাশি",
+    "ছিয়াশি",
+    "সাতাশি",
+    "আটাশি",
+    "ঊননব্বই",
+    "নব্বই",
+    "একানব্বই",
+    "বিরানব্বই",
+    "তিরানব্বই",
+    "চুরানব্বই",
+    "পঁচানব্বই",
+    "ছিয়ানব্বই",
+    "সাতানব্বই",
+    "আটানব্বই",
+    "নিরানব্বই",
+];
+
+/// The traditional words for the days of the month from 1 to 32
+const DAYS: [&str; 32] = [
+    "পয়লা",
+    "দোসরা",
+    "তেসরা",
+    "চৌঠা",
+    "পাঁচই",
+    "ছয়ই",
+    "সাতই",
+    "আটই",
+    "নয়ই",
+    "দশই",
+    "এগারোই",
+    "বারোই",
+    "তেরোই",
+    "চোদ্দোই",
+    "পনেরোই",
+    "ষোলোই",
+    "সতেরোই",
+    "আঠারোই",
+    "উনিশে",
+    "বিশে",
+    "একুশে",
+    "বাইশে",
+    "তেইশে",
+    "চব্বিশে",
+    "পঁচিশে",
+    "ছাব্বিশে",
+    "সাতাশে",
+    "আটাশে",
+    "ঊনত্রিশে",
+    "তিরিশে",
+    "একত্রিশে",
+    "বত্রিশে",
+];
+
+/// The groups of the Indian numbering system with their words, from the largest
+const GROUPS: [(u64, &str); 3] = [(100_000, "লক্ষ"), (1_000, "হাজার"), (100, "শো")];
+
+/// Write a number in Bengali words
+/// # Arguments
+/// * `number` - u64
+/// # Returns
+/// * `String` - The number in words
+/// # Example
+/// ```
+/// use ponjika::words::number_to_words;
+/// assert_eq!(number_to_words(1431), "এক হাজার চারশো একত্রিশ");
+/// assert_eq!(number_to_words(2024), "দুই হাজার চব্বিশ");
+/// assert_eq!(number_to_words(12_500_000), "এক কোটি পঁচিশ লক্ষ");
+/// assert_eq!(number_to_words(0), "শূন্য");
+/// ```
+/// # Note
+/// * The hundreds are joined to their number (চারশো), and the কোটি can have any number of its own (এক হাজার কোটি)
+pub fn number_to_words(number: u64) -> String {
+    if number < 100 {
+        return NUMBERS[number as usize].to_string();
+    }
+
+    let mut words: Vec<String> = Vec::new();
+    let mut rest = number;
+    if rest >= 10_000_000 {
+        words.push(format!("{} কোটি", number_to_words(rest / 10_000_000)));
+        rest %= 10_000_000;
+    }
+    for (size, name) in GROUPS {
+        let count = rest / size;
+        rest %= size;
+        match (count, size) {
+            (0, _) => {}
+            (_, 100) => words.push(format!("{}{}", NUMBERS[count as usize], name)),
+            _ => words.push(format!("{} {}", NUMBERS[count as usize], name)),
+        }
+    }
+    if rest > 0 {
+        words.push(NUMBERS[rest as usize].to_string());
+    }
+
+    words.join(" ")
+}
+
+/// Write a day of the month in the traditional Bengali words
+/// # Arguments
+/// * `day` - u8, the day from 1 to 32
+/// # Returns
+/// * `Result<&str, DateError>` - The day in words
+/// # Example
+/// ```
+/// use ponjika::words::day_to_words;
+/// assert_eq!(day_to_words(1).unwrap(), "পয়লা");
+/// assert_eq!(day_to_words(21).unwrap(), "একুশে");
+/// assert_eq!(day_to_words(25).unwrap(), "পঁচিশে");
+/// assert!(day_to_words(33).is_err());
+/// ```
+/// # Note
+/// * The function will return `DateError::OutOfRange` if the day is not between 1 and 32
+pub fn day_to_words(day: u8) -> Result<&'static str, DateError> {
+    match day {
+        1..=32 => Ok(DAYS[day as usize - 1]),
+        _ => Err(DateError::out_of_range(DateField::Day, day, 1, 32)),
+    }
+}
+
+impl BengaliDate {
+    /// Write the selected date in Bengali words
+    /// # Returns
+    /// * `Result<String, DateError>` - The date written with the `LONG_WORDS` pattern
+    /// # Example
+    /// ```
+    /// use ponjika::date::BengaliDate;
+    /// use ponjika::months::BengaliMonths;
+    /// let date = BengaliDate::create_date(25, BengaliMonths::Baishakh, 1431).unwrap();
+    /// assert_eq!(date.to_long_words().unwrap(), "পঁচিশে বৈশাখ, এক হাজার চারশো একত্রিশ বঙ্গাব্দ");
+    /// ```
+    pub fn to_long_words(&self) -> Result<String, DateError> {
+        self.format(LONG_WORDS)
+    }
+}
+
+impl EnglishDate {
+    /// Write the selected date in Bengali words
+    /// # Returns
+    /// * `Result<String, DateError>` - The date written with the `LONG_WORDS` pattern
+    /// # Example
+    /// ```
+    /// use ponjika::date::EnglishDate;
+    /// use ponjika::months::EnglishMonths;
+    /// let date = EnglishDate::create_date(21, EnglishMonths::February, 1952).unwrap();
+    /// assert_eq!(date.to_long_words().unwrap(), "একুশে ফেব্রুয়ারি, এক হাজার নয়শো বাহান্ন খ্রিস্টাব্দ");
+    /// ```
+    pub fn to_long_words(&self) -> Result<String, DateError> {
+        self.format(LONG_WORDS)
+    }
+}
+
+impl JulianDate {
+    /// Write the selected date in Bengali words
+    /// # Returns
+    /// * `Result<String, DateError>` - The date written with the `LONG_WORDS` pattern
+    pub fn to_long_words(&self) -> Result<String, DateError> {
+        self.format(LONG_WORDS)
+    }
+}
+
+impl Date {
+    /// Write the selected date in Bengali words
+    /// # Returns
+    /// * `Result<String, DateError>` - The date written with the `LONG_WORDS` pattern
+    /// # Note
+    /// * The function will return `DateError::UnknownDate` if the date is `Date::Unknown`
+    pub fn to_long_words(&self) -> Result<String, DateError> {
+        self.format(LONG_WORDS)
+    }
+}